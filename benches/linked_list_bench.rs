@@ -1,4 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use hym::nonull_linked_list::LinkedList as NonNullLinkedList;
+use hym::rc_linked_list::LinkedList as RcLinkedList;
 use hym::LinkedList;
 
 fn bench_push_head(c: &mut Criterion) {
@@ -76,6 +78,61 @@ fn bench_pop_back(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_rc_pop_back(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LinkedList Operations");
+    for size in [1000, 10_000, 100_000].iter() {
+        group.throughput(Throughput::Elements(*size));
+        group.bench_with_input(BenchmarkId::new("rc_pop_back", size), size, |b, &size| {
+            b.iter(|| {
+                let mut list = RcLinkedList::new();
+                for i in 0..size {
+                    list.push_back(black_box(i));
+                }
+                for _ in 0..size {
+                    list.pop_back().unwrap();
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_nonull_rebuild(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LinkedList Operations");
+    for size in [1000, 10_000, 100_000].iter() {
+        group.throughput(Throughput::Elements(*size));
+        // The same list is filled and cleaned on every iteration: the plain list allocates and frees
+        // every node each time, the pooled one only allocates during the first iteration.
+        group.bench_with_input(
+            BenchmarkId::new("nonull_rebuild", size),
+            size,
+            |b, &size| {
+                let mut list = NonNullLinkedList::new();
+                b.iter(|| {
+                    for i in 0..size {
+                        list.push_back(black_box(i));
+                    }
+                    list.clean();
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("nonull_pooled_rebuild", size),
+            size,
+            |b, &size| {
+                let mut list = NonNullLinkedList::with_pool();
+                b.iter(|| {
+                    for i in 0..size {
+                        list.push_back(black_box(i));
+                    }
+                    list.clean();
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
 fn bench_insert(c: &mut Criterion) {
     let mut group = c.benchmark_group("LinkedList Operations");
     for size in [1000, 10_000, 100_000].iter() {
@@ -116,6 +173,8 @@ criterion_group! { benches,
     bench_push_back,
     bench_pop_head,
     bench_pop_back,
+    bench_rc_pop_back,
+    bench_nonull_rebuild,
     bench_insert,
     bench_remove
 }
@@ -1,4 +1,7 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(dead_code)]
 
+extern crate alloc;
+
 mod linear;
 pub use linear::*;
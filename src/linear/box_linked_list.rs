@@ -1,4 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem;
 
 /// `LinkedListNode` represents a single node in a linked list containing a value and a reference to the next node.
 #[derive(Clone, Debug)]
@@ -121,6 +127,8 @@ impl<T: Default> Default for LinkedListNode<T> {
 /// - RemoveOutOfRange: A remove operation is out of range.
 /// - PopFromEmptyList: Trying to pop from an empty list.
 /// - RemoveFromEmptyList: Trying to remove from an empty list.
+/// - ValueNotFound: The value searched for is not in the list.
+/// - IndexOutOfRange: An index is out of range.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LinkedListError {
     RemoveWhileNextIsNone,
@@ -128,6 +136,8 @@ pub enum LinkedListError {
     RemoveOutOfRange,
     PopFromEmptyList,
     RemoveFromEmptyList,
+    ValueNotFound,
+    IndexOutOfRange,
 }
 
 /// A linked list that supports common operations such as adding and removing elements by Box ptr.
@@ -141,7 +151,6 @@ pub enum LinkedListError {
 ///
 /// The `LinkedList` struct represents a linked list data structure. It contains the length of the list, a reference to the first node in the list.
 ///
-#[derive(Clone, Debug)]
 pub struct LinkedList<T> {
     len: usize,
     head: Option<Box<LinkedListNode<T>>>,
@@ -149,7 +158,7 @@ pub struct LinkedList<T> {
 
 impl<T> LinkedList<T>
 where
-    T: std::cmp::PartialEq + Clone,
+    T: cmp::PartialEq + Clone,
 {
     /// Creates a new empty linked list.
     ///
@@ -254,7 +263,7 @@ where
     /// use hym::box_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
-    /// assert_eq!(list.pop_head(), Err(hym::LinkedListError::PopFromEmptyList));
+    /// assert_eq!(list.pop_head(), Err(hym::box_linked_list::LinkedListError::PopFromEmptyList));
     /// ```
     ///
     /// ```rust
@@ -305,7 +314,7 @@ where
     /// use hym::box_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
-    /// assert_eq!(list.pop_back(), Err(hym::LinkedListError::PopFromEmptyList));
+    /// assert_eq!(list.pop_back(), Err(hym::box_linked_list::LinkedListError::PopFromEmptyList));
     /// ```
     ///
     /// ```rust
@@ -375,7 +384,7 @@ where
     /// use hym::box_linked_list::LinkedListError;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
-    /// assert_eq!(list.insert(4, 2), Err(hym::LinkedListError::InsertOutOfRange));
+    /// assert_eq!(list.insert(4, 2), Err(hym::box_linked_list::LinkedListError::InsertOutOfRange));
     /// ```
     ///
     /// # Complexity
@@ -431,7 +440,7 @@ where
     /// use hym::box_linked_list::LinkedListError;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
-    /// assert_eq!(list.remove(1), Err(hym::LinkedListError::RemoveFromEmptyList));
+    /// assert_eq!(list.remove(1), Err(hym::box_linked_list::LinkedListError::RemoveFromEmptyList));
     /// ```
     ///
     /// # Complexity
@@ -574,6 +583,39 @@ where
         self.ix2val(ix)
     }
 
+    /// Retrieves the value at the specified index, or an error saying why it could not.
+    ///
+    /// Same as `get`, but returns a `Result` so it can be used with `?`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ix` - The index of the value to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The value at the specified index.
+    /// * `Err(LinkedListError)` - If the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::{LinkedList, LinkedListError};
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+    /// assert_eq!(list.try_get(1), Ok(2));
+    /// assert_eq!(list.try_get(2), Err(LinkedListError::IndexOutOfRange));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn try_get(&self, ix: usize) -> Result<T, LinkedListError> {
+        self.ix2val(ix).ok_or(LinkedListError::IndexOutOfRange)
+    }
+
     /// Returns the number of elements in the list.
     ///
     /// # Returns
@@ -630,7 +672,7 @@ where
     /// ```
     ///
     pub fn clean(&mut self) {
-        self.head = None;
+        drop_nodes(self.head.take());
         self.len = 0;
     }
 
@@ -650,7 +692,7 @@ where
     /// assert_eq!(iter.next(), Some(&5));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> LinkedListBorrowIterator<T> {
+    pub fn iter(&self) -> LinkedListBorrowIterator<'_, T> {
         LinkedListBorrowIterator::new(self.head.as_ref())
     }
 
@@ -681,466 +723,5205 @@ where
     ///
     /// assert_eq!(format!("{}", list), "(1 -> 4 -> 9 -> 16 -> 25)");
     /// ```
-    pub fn iter_mut(&mut self) -> LinkedListBorrowMutIterator<T> {
+    pub fn iter_mut(&mut self) -> LinkedListBorrowMutIterator<'_, T> {
         LinkedListBorrowMutIterator::new(self.head.as_mut())
     }
-}
 
-impl<T> Default for LinkedList<T> {
-    fn default() -> Self {
-        LinkedList { len: 0, head: None }
-    }
-}
+    /// Returns a reference to the smallest element in the list.
+    ///
+    /// If several elements are equally minimal, the first one is returned.
+    ///
+    /// Named `min_elem` rather than `min`: `LinkedList` itself implements `Ord`, so `list.min()` on
+    /// an owned list would resolve to `Ord::min`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the smallest element.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![3, 1, 4, 1, 5]);
+    /// assert_eq!(list.min_elem(), Some(&1));
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert_eq!(empty.min_elem(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn min_elem(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut iter = self.iter();
+        let mut res = iter.next()?;
 
-impl<T> FromIterator<T> for LinkedList<T>
-where
-    T: Clone + std::cmp::PartialEq,
-{
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut list = LinkedList::new();
         for val in iter {
-            list.push_back(val);
-        }
-        list
-    }
-}
-
-impl<T: fmt::Display> fmt::Display for LinkedList<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.len == 0 {
-            return write!(f, "()"); // Empty list
+            if *val < *res {
+                res = val;
+            }
         }
 
-        write!(f, "(")?;
+        Some(res)
+    }
 
-        let mut curr = self.head.as_ref().unwrap();
-        let mut first = true;
+    /// Returns a reference to the largest element in the list.
+    ///
+    /// If several elements are equally maximal, the first one is returned.
+    ///
+    /// Named `max_elem` rather than `max`: `LinkedList` itself implements `Ord`, so `list.max()` on
+    /// an owned list would resolve to `Ord::max`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the largest element.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![3, 1, 4, 1, 5]);
+    /// assert_eq!(list.max_elem(), Some(&5));
+    ///
+    /// let empty: LinkedList<i32> = LinkedList::new();
+    /// assert_eq!(empty.max_elem(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn max_elem(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut iter = self.iter();
+        let mut res = iter.next()?;
 
-        for _ in 0..self.len {
-            if !first {
-                write!(f, " -> ")?;
-            }
-            write!(f, "{}", curr.value)?;
-            first = false;
-            if curr.next.is_some() {
-                curr = curr.next.as_ref().unwrap();
+        for val in iter {
+            if *val > *res {
+                res = val;
             }
         }
 
-        write!(f, ")")?;
-        Ok(())
-    }
-}
-
-impl<T: Clone> IntoIterator for LinkedList<T> {
-    type Item = T;
-    type IntoIter = LinkedListIterator<T>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        LinkedListIterator::new(self.head)
+        Some(res)
     }
-}
-
-/// Iterator for LinkedList<T>
-pub struct LinkedListIterator<T> {
-    current: Option<Box<LinkedListNode<T>>>,
-}
 
-impl<T> LinkedListIterator<T> {
-    pub fn new(head: Option<Box<LinkedListNode<T>>>) -> LinkedListIterator<T> {
-        LinkedListIterator { current: head }
+    /// Returns a cursor positioned at the first element of the list.
+    ///
+    /// The cursor can walk forward through the list and insert or remove elements around its
+    /// position in O(1), instead of re-walking from the head on every edit like `insert`/`remove` do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// cursor.insert_after(3);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4)");
+    /// assert_eq!(list.len(), 4);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut::new(&mut self.head, &mut self.len)
     }
-}
 
-impl<T> Iterator for LinkedListIterator<T> {
-    type Item = T;
+    /// Inserts a value into an ascending sorted list, keeping it sorted.
+    ///
+    /// The value is placed right before the first element greater than it, so equal values are kept
+    /// in insertion order (after the existing equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to be inserted.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The index at which the value was inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5]);
+    /// assert_eq!(list.insert_sorted(4), 2);
+    /// assert_eq!(format!("{}", list), "(1 -> 3 -> 4 -> 5)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn insert_sorted(&mut self, val: T) -> usize
+    where
+        T: Ord,
+    {
+        let mut ix = 0;
+        let mut link = &mut self.head;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.current.take() {
-            self.current = node.next;
-            Some(node.value)
-        } else {
-            None
+        // stop at the link pointing to the first element greater than `val`
+        while link.as_ref().is_some_and(|node| node.value <= val) {
+            link = &mut link.as_mut().unwrap().next;
+            ix += 1;
         }
-    }
-}
-
-/// Borrow iterators for LinkedList<T>
-pub struct LinkedListBorrowIterator<'a, T> {
-    current: Option<&'a Box<LinkedListNode<T>>>,
-}
 
-impl<'a, T> LinkedListBorrowIterator<'a, T> {
-    pub fn new(head: Option<&'a Box<LinkedListNode<T>>>) -> LinkedListBorrowIterator<'a, T> {
-        LinkedListBorrowIterator { current: head }
+        *link = Some(Box::new(LinkedListNode::new(val, link.take())));
+        self.len += 1;
+        ix
     }
-}
-
-impl<'a, T> Iterator for LinkedListBorrowIterator<'a, T> {
-    type Item = &'a T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.current.take() {
-            self.current = node.next.as_ref();
-            Some(&node.value)
-        } else {
-            None
-        }
+    /// Returns a reference to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the head value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.first(), Some(&1));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn first(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
     }
-}
-
-/// Borrow Mut iter for LinkedList<T>
-pub struct LinkedListBorrowMutIterator<'a, T> {
-    current: Option<&'a mut Box<LinkedListNode<T>>>,
-}
 
-impl<'a, T> LinkedListBorrowMutIterator<'a, T> {
-    pub fn new(head: Option<&'a mut Box<LinkedListNode<T>>>) -> LinkedListBorrowMutIterator<'a, T> {
-        LinkedListBorrowMutIterator { current: head }
+    /// Returns a reference to the last element of the list.
+    ///
+    /// The list keeps no tail pointer, so this walks from the head.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the tail value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.last(), Some(&3));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn last(&self) -> Option<&T> {
+        self.iter().last()
     }
-}
-
-impl<'a, T> Iterator for LinkedListBorrowMutIterator<'a, T> {
-    type Item = &'a mut T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.current.take() {
-            self.current = node.next.as_mut();
-            Some(&mut node.value)
-        } else {
-            None
-        }
+    /// Counts the occurrences of a given value in the list.
+    ///
+    /// Unlike `val2ix(&val).len()`, this allocates nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to count.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of elements equal to `val`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1, 3, 1]);
+    /// assert_eq!(list.count(&1), 3);
+    /// assert_eq!(list.count(&4), 0);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn count(&self, val: &T) -> usize {
+        self.iter().filter(|&x| x == val).count()
     }
-}
 
-// Unit Test for LinkedList
+    /// Creates a list from a slice of `Copy` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `slice` - The elements to copy into the list, in order.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new list holding a copy of every element of `slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from_slice(&[1, 2, 3]);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn from_slice(slice: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        for &val in slice {
+            cursor.insert_after(val); // the cursor stays at the end, so this appends in O(1)
+        }
+        list
+    }
+
+    /// Returns a mutable reference to the first element of the list.
+    ///
+    /// This is the mutable counterpart of `first`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` - A mutable reference to the head value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// if let Some(head) = list.peek_mut() {
+    ///     *head = 10;
+    /// }
+    /// assert_eq!(format!("{}", list), "(10 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.value)
+    }
+
+    /// Removes every element matching a predicate and returns the removed elements.
+    ///
+    /// The elements that do not match stay in the list, in their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<T>` - The removed elements, in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(1..=6);
+    /// assert_eq!(list.extract_if(|x| x % 2 == 0), vec![2, 4, 6]);
+    /// assert_eq!(format!("{}", list), "(1 -> 3 -> 5)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(k)             |
+    ///
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        let mut cursor = self.cursor_front_mut();
+        while let Some(val) = cursor.current() {
+            if pred(val) {
+                removed.extend(cursor.remove_current()); // the next candidate is now current
+            } else {
+                cursor.move_next();
+            }
+        }
+        removed
+    }
+
+    /// Checks whether the list begins with the given sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The sequence to look for at the head of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the list begins with `prefix`, or `prefix` is empty.
+    /// * `false` - If they differ or the list is shorter than `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert!(list.starts_with(&[1, 2]));
+    /// assert!(!list.starts_with(&[2]));
+    /// assert!(list.starts_with(&[]));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(m)            | O(1)             |
+    ///
+    pub fn starts_with(&self, prefix: &[T]) -> bool {
+        let mut iter = self.iter();
+        prefix.iter().all(|p| iter.next() == Some(p))
+    }
+
+    /// Replaces the value at a specific index and returns the previous value.
+    ///
+    /// # Arguments
+    ///
+    /// * `ix` - The index of the value to replace.
+    /// * `val` - The new value.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The value previously at `ix`.
+    /// * `Err(LinkedListError)` - If the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.replace(1, 20), Ok(2));
+    /// assert_eq!(format!("{}", list), "(1 -> 20 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn replace(&mut self, ix: usize, val: T) -> Result<T, LinkedListError> {
+        match self.iter_mut().nth(ix) {
+            Some(slot) => Ok(mem::replace(slot, val)),
+            None => Err(LinkedListError::RemoveOutOfRange),
+        }
+    }
+
+    /// Splits the list into the elements that match a predicate and those that do not.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements that go into the first list.
+    ///
+    /// # Returns
+    ///
+    /// * `(LinkedList<T>, LinkedList<T>)` - The matching and the non-matching elements, each in
+    ///   their original relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(1..=6);
+    /// let (even, odd) = list.partition(|x| x % 2 == 0);
+    /// assert_eq!(format!("{}", even), "(2 -> 4 -> 6)");
+    /// assert_eq!(format!("{}", odd), "(1 -> 3 -> 5)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn partition<F: FnMut(&T) -> bool>(self, mut pred: F) -> (LinkedList<T>, LinkedList<T>) {
+        let (mut matches, mut rest) = (LinkedList::new(), LinkedList::new());
+        let mut matches_end = matches.cursor_front_mut();
+        let mut rest_end = rest.cursor_front_mut();
+        for val in self {
+            // Both cursors stay at the end of their list, so each value is appended in O(1)
+            if pred(&val) {
+                matches_end.insert_after(val);
+            } else {
+                rest_end.insert_after(val);
+            }
+        }
+        (matches, rest)
+    }
+
+    /// Returns an iterator over clones of the list's elements, `n` at a time.
+    ///
+    /// The last chunk is shorter than `n` if the length of the list is not a multiple of `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The size of each chunk.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = Vec<T>>` - The chunks, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, like `slice::chunks`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+    /// let mut chunks = list.chunks(2);
+    /// assert_eq!(chunks.next(), Some(vec![1, 2]));
+    /// assert_eq!(chunks.next(), Some(vec![3, 4]));
+    /// assert_eq!(chunks.next(), Some(vec![5]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn chunks(&self, n: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+        assert!(n != 0, "chunk size must be non-zero");
+        let mut iter = self.iter();
+        core::iter::from_fn(move || {
+            let chunk: Vec<T> = iter.by_ref().take(n).cloned().collect();
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(chunk)
+            }
+        })
+    }
+
+    /// Returns an iterator over all overlapping windows of `n` consecutive elements.
+    ///
+    /// Like `slice::windows`, but since the nodes are not contiguous in memory each window is a
+    /// `Vec` of references rather than a sub-slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The size of each window.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = Vec<&T>>` - The windows, in order; none if `n` exceeds the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, like `slice::windows`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(1..=3);
+    /// let mut windows = list.windows(2);
+    /// assert_eq!(windows.next(), Some(vec![&1, &2]));
+    /// assert_eq!(windows.next(), Some(vec![&2, &3]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n * k)        | O(k)             |
+    ///
+    pub fn windows(&self, n: usize) -> impl Iterator<Item = Vec<&T>> + '_ {
+        assert!(n != 0, "window size must be non-zero");
+        let count = (self.len + 1).saturating_sub(n);
+        let mut start = self.iter();
+        (0..count).map(move |_| {
+            let window = start.clone().take(n).collect();
+            start.next();
+            window
+        })
+    }
+
+    /// Returns the index of the last element matching a predicate.
+    ///
+    /// The list is singly linked, so this walks the whole list from the head and remembers the
+    /// last match.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The highest index whose element matches.
+    /// * `None` - If no element matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// assert_eq!(list.rposition(|x| x % 2 == 1), Some(2));
+    /// assert_eq!(list.rposition(|&x| x > 4), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn rposition<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, val)| pred(val))
+            .last()
+            .map(|(ix, _)| ix)
+    }
+
+    /// Splits the list into two borrowing iterators, over `[0, mid)` and `[mid, len)`.
+    ///
+    /// Nothing is cloned. If `mid` is at least the length of the list, the first iterator covers
+    /// the whole list and the second one is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `mid` - The index the second half starts at.
+    ///
+    /// # Returns
+    ///
+    /// * `(impl Iterator<Item = &T>, impl Iterator<Item = &T>)` - The two halves, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+    /// let (left, right) = list.split_at(2);
+    /// assert_eq!(left.sum::<i32>(), 3);
+    /// assert_eq!(right.sum::<i32>(), 12);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(mid)          | O(1)             |
+    ///
+    pub fn split_at(&self, mid: usize) -> (impl Iterator<Item = &T>, impl Iterator<Item = &T>) {
+        let mut right = self.iter();
+        for _ in 0..mid {
+            if right.next().is_none() {
+                break;
+            }
+        }
+        (self.iter().take(mid), right)
+    }
+
+    /// Returns a value that displays the list with a custom separator.
+    ///
+    /// By default the elements are written without surrounding brackets; call `brackets` on the
+    /// result to add them. The `Display` impl of the list itself is unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `sep` - The string written between two elements.
+    ///
+    /// # Returns
+    ///
+    /// * `DisplayWith<'a, T>` - A wrapper implementing `Display`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.display_with(", ").to_string(), "1, 2, 3");
+    /// assert_eq!(list.display_with(" ").brackets("[", "]").to_string(), "[1 2 3]");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn display_with<'a>(&'a self, sep: &'a str) -> DisplayWith<'a, T>
+    where
+        T: fmt::Display,
+    {
+        DisplayWith {
+            list: self,
+            sep,
+            open: "",
+            close: "",
+        }
+    }
+
+    /// Searches a sorted list for a value.
+    ///
+    /// Like `slice::binary_search`, but the list has no random access, so this is a linear scan
+    /// from the head: O(n), not O(log n). The list must be sorted in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to search for.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The index of the first element equal to `val`.
+    /// * `Err(usize)` - The index where `val` could be inserted to keep the list sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5]);
+    /// assert_eq!(list.search_sorted(&3), Ok(1));
+    /// assert_eq!(list.search_sorted(&4), Err(2));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn search_sorted(&self, val: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        for (ix, x) in self.iter().enumerate() {
+            match x.cmp(val) {
+                cmp::Ordering::Less => {}
+                cmp::Ordering::Equal => return Ok(ix),
+                cmp::Ordering::Greater => return Err(ix),
+            }
+        }
+        Err(self.len)
+    }
+
+    /// Moves the whole list out, leaving an empty list in its place.
+    ///
+    /// This is `core::mem::take(self)`, exposed as a method.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A list holding everything `self` held.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let taken = list.take();
+    /// assert_eq!(format!("{}", taken), "(1 -> 2 -> 3)");
+    /// assert!(list.is_empty());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+
+    /// Inserts all items of an iterator at the front of the list, keeping their order.
+    ///
+    /// Calling `push_head` for each item would reverse them; instead the items are collected into a
+    /// new list, which is then linked in front of the current head.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The items to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![4, 5]);
+    /// list.prepend(vec![1, 2, 3]);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(k)            | O(k)             |
+    ///
+    pub fn prepend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut front: LinkedList<T> = iter.into_iter().collect();
+
+        let mut link = &mut front.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        *link = self.head.take();
+
+        front.len += self.len;
+        self.len = 0;
+        *self = front;
+    }
+
+    /// Pairs up the elements of two lists into a new list of tuples.
+    ///
+    /// The result is as long as the shorter of the two lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list providing the second element of each pair.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<(T, U)>` - Clones of the elements at the same index in both lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let nums: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let chars: LinkedList<char> = LinkedList::from_iter(vec!['a', 'b']);
+    /// let pairs = nums.zip(&chars);
+    /// assert_eq!(pairs.first(), Some(&(1, 'a')));
+    /// assert_eq!(pairs.len(), 2);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn zip<U: Clone + cmp::PartialEq>(&self, other: &LinkedList<U>) -> LinkedList<(T, U)> {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect()
+    }
+
+    /// Returns an iterator over the elements of the list together with their indices.
+    ///
+    /// This is `self.iter().enumerate()`.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = (usize, &T)>` - Each index paired with a reference to its element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<char> = LinkedList::from_iter(vec!['a', 'b']);
+    /// let mut iter = list.indexed_iter();
+    /// assert_eq!(iter.next(), Some((0, &'a')));
+    /// assert_eq!(iter.next(), Some((1, &'b')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.iter().enumerate()
+    }
+
+    /// Returns an iterator over the elements paired with their indices, for changing them in place.
+    ///
+    /// This is `self.iter_mut().enumerate()`, the mutable counterpart of `indexed_iter`.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = (usize, &mut T)>` - Each index paired with a mutable reference to its
+    ///   element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<usize> = LinkedList::from_iter(vec![10, 10, 10]);
+    /// for (ix, val) in list.iter_mut_indexed() {
+    ///     *val += ix;
+    /// }
+    /// assert_eq!(format!("{}", list), "(10 -> 11 -> 12)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.iter_mut().enumerate()
+    }
+
+    /// Checks whether the elements are in non-decreasing order.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If every element is `<=` the next one, which always holds for 0 or 1 elements.
+    /// * `false` - As soon as an element is found that is not `<=` its successor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// assert!(LinkedList::from_iter(vec![1, 2, 2, 3]).is_sorted());
+    /// assert!(!LinkedList::from_iter(vec![1, 3, 2]).is_sorted());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks whether every pair of neighbouring elements satisfies a comparison.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - Returns `true` if its first argument may come before its second.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If `compare(a, b)` holds for every element `a` and its successor `b`.
+    /// * `false` - As soon as it does not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![3, 2, 1]);
+    /// assert!(list.is_sorted_by(|a, b| a >= b));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .all(|(a, b)| compare(a, b))
+    }
+
+    /// Removes every element equal to a given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of elements removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1, 1, 3]);
+    /// assert_eq!(list.remove_all(&1), 3);
+    /// assert_eq!(format!("{}", list), "(2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn remove_all(&mut self, val: &T) -> usize {
+        let mut removed = 0;
+        let mut cursor = self.cursor_front_mut();
+        while let Some(current) = cursor.current() {
+            if current == val {
+                cursor.remove_current(); // no `move_next`, the following element is now current
+                removed += 1;
+            } else {
+                cursor.move_next();
+            }
+        }
+        removed
+    }
+
+    /// Returns a reference to the `n`-th element counted from the tail, `n = 0` being the last one.
+    ///
+    /// Uses two pointers instead of the length: a lead pointer starts `n + 1` nodes ahead, then
+    /// both move together until the lead runs off the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The position counted from the tail.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the element.
+    /// * `None` - If `n` is not less than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.nth_from_end(0), Some(&3));
+    /// assert_eq!(list.nth_from_end(2), Some(&1));
+    /// assert_eq!(list.nth_from_end(3), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        let mut lead = self.iter();
+        for _ in 0..=n {
+            lead.next()?;
+        }
+
+        let mut trail = self.iter();
+        for _ in lead {
+            trail.next();
+        }
+        trail.next()
+    }
+
+    /// Moves all elements of another list into this one, starting at a specific index.
+    ///
+    /// The nodes of `other` are relinked, not cloned. Splicing at 0 prepends `other`, splicing at
+    /// the length appends it.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index the first element of `other` ends up at.
+    /// * `other` - The list to insert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the splice is successful.
+    /// * `Err(LinkedListError)` - If the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 4]);
+    /// list.splice(1, LinkedList::from_iter(vec![2, 3])).unwrap();
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// The list keeps no tail pointer, so finding the end of `other` takes O(m) for `m` elements.
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(at + m)       | O(1)             |
+    ///
+    pub fn splice(&mut self, at: usize, mut other: LinkedList<T>) -> Result<(), LinkedListError> {
+        if at > self.len {
+            return Err(LinkedListError::InsertOutOfRange);
+        }
+
+        let mut link = &mut self.head;
+        for _ in 0..at {
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        // Hang `other` off the link, then the rest of this list off the end of `other`
+        let rest = link.take();
+        *link = other.head.take();
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        *link = rest;
+
+        self.len += other.len;
+        Ok(())
+    }
+
+    /// Resizes the list to `new_len` elements, appending clones of `value` or dropping elements
+    /// from the back as needed, like `Vec::resize`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_len` - The length of the list afterwards.
+    /// * `value` - The value cloned into each new element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+    /// list.resize(4, 0);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 0 -> 0)");
+    /// list.resize(1, 0);
+    /// assert_eq!(format!("{}", list), "(1)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(max(n, new_len)) | O(1)           |
+    ///
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        self.resize_with(new_len, || value.clone());
+    }
+
+    /// Resizes the list to `new_len` elements, appending values returned by `f` or dropping
+    /// elements from the back as needed, like `Vec::resize_with`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_len` - The length of the list afterwards.
+    /// * `f` - Called once for each new element, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// let mut next = 0;
+    /// list.resize_with(3, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(max(n, new_len)) | O(1)           |
+    ///
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        let mut link = &mut self.head;
+        for _ in 0..new_len.min(self.len) {
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        // Drop the nodes past `new_len`
+        drop_nodes(link.take());
+
+        for _ in self.len..new_len {
+            link = &mut link.insert(Box::new(LinkedListNode::new(f(), None))).next;
+        }
+
+        self.len = new_len;
+    }
+
+    /// Returns an iterator over clones of the list's elements, `n` at a time, starting from the back.
+    ///
+    /// Like `slice::rchunks`: each chunk keeps the list order, and the first chunk holds the last `n`
+    /// elements. The list is singly linked, so references to all elements are collected into a `Vec`
+    /// up front and chunked from its end.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The size of each chunk.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = Vec<T>>` - The chunks, from the back of the list to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, like `slice::rchunks`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+    /// let mut chunks = list.rchunks(2);
+    /// assert_eq!(chunks.next(), Some(vec![4, 5]));
+    /// assert_eq!(chunks.next(), Some(vec![2, 3]));
+    /// assert_eq!(chunks.next(), Some(vec![1]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn rchunks(&self, n: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+        assert!(n != 0, "chunk size must be non-zero");
+        let items: Vec<&T> = self.iter().collect();
+        let mut end = items.len();
+        core::iter::from_fn(move || {
+            if end == 0 {
+                return None;
+            }
+            let start = end.saturating_sub(n);
+            let chunk = items[start..end].iter().map(|&val| val.clone()).collect();
+            end = start;
+            Some(chunk)
+        })
+    }
+
+    /// Merges two sorted lists into one sorted list.
+    ///
+    /// Both lists must be sorted in ascending order. Their nodes are relinked, not cloned, and the
+    /// merge is stable: of two equal elements, the one from `a` comes first.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first sorted list.
+    /// * `b` - The second sorted list.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<T>` - A sorted list holding all elements of `a` and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5]);
+    /// let b: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6]);
+    /// let merged = LinkedList::merge(a, b);
+    /// assert_eq!(format!("{}", merged), "(1 -> 2 -> 3 -> 4 -> 5 -> 6)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n + m)        | O(1)             |
+    ///
+    pub fn merge(mut a: LinkedList<T>, mut b: LinkedList<T>) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut merged = LinkedList::new();
+        merged.len = a.len + b.len;
+
+        let (mut left, mut right) = (a.head.take(), b.head.take());
+        let mut link = &mut merged.head;
+        loop {
+            let from = match (&left, &right) {
+                (Some(l), Some(r)) if l.value <= r.value => &mut left,
+                (Some(_), Some(_)) => &mut right,
+                // One side is used up: the other one is already sorted and can be linked in whole
+                (_, None) => {
+                    *link = left;
+                    break;
+                }
+                (None, _) => {
+                    *link = right;
+                    break;
+                }
+            };
+
+            let mut node = from.take().unwrap();
+            *from = node.next.take();
+            link = &mut link.insert(node).next;
+        }
+
+        merged
+    }
+
+    /// Splits the list into runs of consecutive equal elements.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Vec<T>>` - Clones of the elements, one `Vec` per run, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 1, 2, 3, 3]);
+    /// assert_eq!(list.group_runs(), vec![vec![1, 1], vec![2], vec![3, 3]]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn group_runs(&self) -> Vec<Vec<T>> {
+        let mut groups: Vec<Vec<T>> = Vec::new();
+        for val in self.iter() {
+            match groups.last_mut() {
+                Some(group) if group.last() == Some(val) => group.push(val.clone()),
+                _ => groups.push(vec![val.clone()]),
+            }
+        }
+        groups
+    }
+
+    /// Removes and returns the value at the head of the list, or `None` if it is empty.
+    ///
+    /// Same as `pop_head`, but returns an `Option` like `VecDeque::pop_front`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The value from the removed head node.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1]);
+    /// assert_eq!(list.pop_head_opt(), Some(1));
+    /// assert_eq!(list.pop_head_opt(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn pop_head_opt(&mut self) -> Option<T> {
+        self.pop_head().ok()
+    }
+
+    /// Removes and returns the value at the back of the list, or `None` if it is empty.
+    ///
+    /// Same as `pop_back`, but returns an `Option` like `VecDeque::pop_back`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The value from the removed tail node.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1]);
+    /// assert_eq!(list.pop_back_opt(), Some(1));
+    /// assert_eq!(list.pop_back_opt(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn pop_back_opt(&mut self) -> Option<T> {
+        self.pop_back().ok()
+    }
+
+    /// Inserts clones of all values in a slice, starting at a specific index and keeping their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index the first value ends up at.
+    /// * `vals` - The values to insert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the insertion is successful.
+    /// * `Err(LinkedListError)` - If the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 5]);
+    /// list.insert_many(1, &[2, 3, 4]).unwrap();
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(at + k)       | O(k)             |
+    ///
+    pub fn insert_many(&mut self, at: usize, vals: &[T]) -> Result<(), LinkedListError> {
+        if at > self.len {
+            return Err(LinkedListError::InsertOutOfRange);
+        }
+        self.splice(at, vals.iter().cloned().collect())
+    }
+
+    /// Does nothing; exists for API parity with `Vec::reserve`.
+    ///
+    /// Every node of this list is a separate `Box`, allocated when its element is pushed and freed
+    /// when it is removed, so there is no capacity to reserve ahead of time. Pushing always
+    /// allocates. The NonNull list's `with_pool`/`reserve` can preallocate nodes if that matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - Ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// list.reserve(10); // no-op
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Returns the first element and an iterator over the rest, like `slice::split_first`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&T, impl Iterator<Item = &T>))` - The head value and the elements after it.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let (first, rest) = list.split_first().unwrap();
+    /// assert_eq!(first, &1);
+    /// assert_eq!(rest.collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    ///
+    pub fn split_first(&self) -> Option<(&T, impl Iterator<Item = &T>)> {
+        let mut iter = self.iter();
+        iter.next().map(|first| (first, iter))
+    }
+
+    /// Returns the last element and an iterator over the elements before it, like `slice::split_last`.
+    ///
+    /// The list keeps no tail pointer, so this walks from the head to find the last element.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&T, impl Iterator<Item = &T>))` - The tail value and the elements before it, in order.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let (last, init) = list.split_last().unwrap();
+    /// assert_eq!(last, &3);
+    /// assert_eq!(init.collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn split_last(&self) -> Option<(&T, impl Iterator<Item = &T>)> {
+        self.last()
+            .map(|last| (last, self.iter().take(self.len - 1)))
+    }
+
+    /// Appends a clone of every element, in order, to the end of `buf`.
+    ///
+    /// The caller owns the buffer, so it can reserve space up front or reuse one buffer across
+    /// calls. Whatever `buf` already holds is kept in front of the new elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The buffer to append the elements to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// list.push_back(2);
+    /// list.push_back(3);
+    /// let mut buf = vec![1];
+    /// list.collect_into(&mut buf);
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn collect_into(&self, buf: &mut Vec<T>) {
+        buf.reserve(self.len);
+        buf.extend(self.iter().cloned());
+    }
+
+    /// Lazily yields the indices of every element equal to `val`, the iterator form of `val2ix`.
+    ///
+    /// Nothing is allocated and the list is only walked as far as the caller pulls, so
+    /// `list.indices_of(&x).next()` stops at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to search for in the list.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the matching indices, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(1);
+    /// assert_eq!(list.indices_of(&1).collect::<Vec<_>>(), vec![0, 2]);
+    /// assert_eq!(list.indices_of(&1).next(), Some(0));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn indices_of<'a>(&'a self, val: &'a T) -> impl Iterator<Item = usize> + 'a {
+        self.iter()
+            .enumerate()
+            .filter(move |(_, x)| *x == val)
+            .map(|(ix, _)| ix)
+    }
+
+    /// Keeps only the elements for which the predicate returns `true`, like `Vec::retain_mut`.
+    ///
+    /// The predicate gets a mutable reference, so it can change the elements it keeps. The others
+    /// are unlinked and dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Returns `true` for the elements to keep, and may modify them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(1..=6);
+    /// list.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x > 30
+    /// });
+    /// assert_eq!(format!("{}", list), "(40 -> 50 -> 60)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(val) = cursor.current_mut() {
+            if f(val) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current(); // leaves the cursor on the element after the dropped one
+            }
+        }
+    }
+
+    /// Inserts a value right after the first element equal to `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The value to look for.
+    /// * `val` - The value to insert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the value was inserted.
+    /// * `Err(LinkedListError)` - If no element equals `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::{LinkedList, LinkedListError};
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.insert_after_first(&2, 4), Ok(()));
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 4 -> 3)");
+    /// assert_eq!(list.insert_after_first(&5, 6), Err(LinkedListError::ValueNotFound));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn insert_after_first(&mut self, target: &T, val: T) -> Result<(), LinkedListError> {
+        let ix = self
+            .iter()
+            .position(|x| x == target)
+            .ok_or(LinkedListError::ValueNotFound)?;
+        self.insert(val, ix + 1)
+    }
+
+    /// Inserts a value right before the first element equal to `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The value to look for.
+    /// * `val` - The value to insert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the value was inserted.
+    /// * `Err(LinkedListError)` - If no element equals `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::{LinkedList, LinkedListError};
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.insert_before_first(&2, 4), Ok(()));
+    /// assert_eq!(format!("{}", list), "(1 -> 4 -> 2 -> 3)");
+    /// assert_eq!(list.insert_before_first(&5, 6), Err(LinkedListError::ValueNotFound));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn insert_before_first(&mut self, target: &T, val: T) -> Result<(), LinkedListError> {
+        let ix = self
+            .iter()
+            .position(|x| x == target)
+            .ok_or(LinkedListError::ValueNotFound)?;
+        self.insert(val, ix)
+    }
+
+    /// Creates a list of `n` elements where element `i` is `f(i)`, like `std::array::from_fn`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The length of the new list.
+    /// * `f` - Called with each index from `0` to `n - 1`, in order.
+    ///
+    /// # Returns
+    ///
+    /// A new `LinkedList` of length `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<usize> = LinkedList::from_fn(4, |i| i * i);
+    /// assert_eq!(format!("{}", list), "(0 -> 1 -> 4 -> 9)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, f: F) -> Self {
+        // `collect` keeps a tail link, where `n` calls to `push_back` would each walk the list.
+        (0..n).map(f).collect()
+    }
+
+    /// Checks whether two lists are equal under a custom comparison, possibly of different types.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare with.
+    /// * `f` - Returns `true` if an element of this list matches the element of `other` at the
+    ///   same position.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If both lists have the same length and `f` returns `true` for every pair.
+    /// * `false` - Otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+    /// let b: LinkedList<i64> = LinkedList::from_iter(vec![1, 2]);
+    /// assert!(a.eq_by(&b, |x, y| *x as i64 == *y));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn eq_by<U, F: FnMut(&T, &U) -> bool>(&self, other: &LinkedList<U>, mut f: F) -> bool {
+        self.len == other.len
+            && self
+                .iter()
+                .zip(LinkedListBorrowIterator::new(other.head.as_ref()))
+                .all(|(a, b)| f(a, b))
+    }
+
+    /// Compares two lists lexicographically under a custom comparison, possibly of different types.
+    ///
+    /// Pairs are compared in order until one is not `Equal`; if one list runs out first, it is the
+    /// smaller one, as with `Ord` for lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare with.
+    /// * `f` - Compares an element of this list with the element of `other` at the same position.
+    ///
+    /// # Returns
+    ///
+    /// The `Ordering` of this list relative to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+    /// let b: LinkedList<i64> = LinkedList::from_iter(vec![1, 3]);
+    /// assert_eq!(a.cmp_by(&b, |x, y| (*x as i64).cmp(y)), Ordering::Less);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn cmp_by<U, F: FnMut(&T, &U) -> cmp::Ordering>(
+        &self,
+        other: &LinkedList<U>,
+        mut f: F,
+    ) -> cmp::Ordering {
+        let mut rhs = LinkedListBorrowIterator::new(other.head.as_ref());
+        for a in self.iter() {
+            match rhs.next() {
+                Some(b) => match f(a, b) {
+                    cmp::Ordering::Equal => {}
+                    ord => return ord,
+                },
+                None => return cmp::Ordering::Greater,
+            }
+        }
+        if rhs.next().is_some() {
+            cmp::Ordering::Less
+        } else {
+            cmp::Ordering::Equal
+        }
+    }
+
+    /// Collapses each run of consecutive equal elements into a `(value, count)` pair.
+    ///
+    /// The counting form of `group_runs`; `from_run_length` turns the pairs back into a list.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(T, usize)>` - One pair per run, in order. Empty for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 1, 1, 2, 3, 3]);
+    /// assert_eq!(list.run_length_encode(), vec![(1, 3), (2, 1), (3, 2)]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(k)             |
+    ///
+    pub fn run_length_encode(&self) -> Vec<(T, usize)> {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        for val in self.iter() {
+            match runs.last_mut() {
+                Some((last, count)) if last == val => *count += 1,
+                _ => runs.push((val.clone(), 1)),
+            }
+        }
+        runs
+    }
+
+    /// Creates a list from `(value, count)` pairs, repeating each value `count` times.
+    ///
+    /// The inverse of `run_length_encode`. Pairs with a count of `0` add nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The values and how many times each one appears, in order.
+    ///
+    /// # Returns
+    ///
+    /// A new `LinkedList` holding the expanded values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_run_length(&[(1, 2), (2, 1)]);
+    /// assert_eq!(format!("{}", list), "(1 -> 1 -> 2)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn from_run_length(pairs: &[(T, usize)]) -> Self {
+        pairs
+            .iter()
+            .flat_map(|(val, count)| core::iter::repeat_n(val, *count).cloned())
+            .collect()
+    }
+
+    /// Returns an iterator over clones of the elements, from head to tail.
+    ///
+    /// The same as `iter().cloned()`: unlike `into_iter`, the list is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding owned copies of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.iter_cloned().collect::<Vec<i32>>(), vec![1, 2, 3]);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn iter_cloned(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().cloned()
+    }
+
+    /// Rotates the list in place so that the first `n % len` elements move to the back.
+    ///
+    /// The list is cut after the `n % len`-th node and the front part is linked onto the end of the
+    /// rest; no values are moved or cloned. Does nothing if the list has fewer than two elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of positions to rotate by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+    /// list.rotate_left(2);
+    /// assert_eq!(format!("{}", list), "(3 -> 4 -> 5 -> 1 -> 2)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+        let k = n % self.len;
+        if k == 0 {
+            return;
+        }
+
+        // Cut after the first `k` nodes.
+        let mut link = &mut self.head;
+        for _ in 0..k {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        let mut rest = link.take();
+
+        // Link the front part onto the end of the rest.
+        let mut end = &mut rest;
+        while let Some(node) = end {
+            end = &mut node.next;
+        }
+        *end = self.head.take();
+        self.head = rest;
+    }
+
+    /// Rotates the list in place so that the last `n % len` elements move to the front.
+    ///
+    /// The counterpart of `rotate_left`. Does nothing if the list has fewer than two elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of positions to rotate by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+    /// list.rotate_right(1);
+    /// assert_eq!(format!("{}", list), "(5 -> 1 -> 2 -> 3 -> 4)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+        self.rotate_left(self.len - n % self.len);
+    }
+
+    /// Returns the first element equal to `val`, pushing `val` to the back first if there is none.
+    ///
+    /// Useful for small ordered sets kept in insertion order.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to look for, and to push if it is missing.
+    ///
+    /// # Returns
+    ///
+    /// * `&T` - A reference to the existing element, or to the newly pushed one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+    /// assert_eq!(list.get_or_insert_back(2), &2);
+    /// assert_eq!(list.get_or_insert_back(3), &3);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn get_or_insert_back(&mut self, val: T) -> &T {
+        let mut link = &mut self.head;
+        while link.as_ref().is_some_and(|node| node.value != val) {
+            link = &mut link.as_mut().unwrap().next;
+        }
+
+        // Either the matching node, or the empty link at the end of the list.
+        if link.is_none() {
+            *link = Some(Box::new(LinkedListNode::new(val, None)));
+            self.len += 1;
+        }
+        &link.as_ref().unwrap().value
+    }
+
+    /// Folds the elements from the tail toward the head, like `Iterator::rfold`.
+    ///
+    /// The list is singly linked and cannot be walked backwards, so references to the elements are
+    /// first collected into a temporary `Vec`, which takes O(n) extra space.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator.
+    /// * `f` - Combines the accumulator with the next element, starting from the last one.
+    ///
+    /// # Returns
+    ///
+    /// The final value of the accumulator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.rfold(Vec::new(), |mut acc, x| { acc.push(*x); acc }), vec![3, 2, 1]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn rfold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        let refs: Vec<&T> = self.iter().collect();
+        refs.into_iter().rev().fold(init, f)
+    }
+
+    /// Removes consecutive elements whose keys are equal, keeping the first of each run, like
+    /// `Vec::dedup_by_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Extracts the key that elements are compared by.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = LinkedList::from_iter(vec!["a", "b", "cd", "ef", "g"]);
+    /// list.dedup_by_key(|s| s.len());
+    /// assert_eq!(format!("{}", list), "(a -> cd -> g)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        let mut prev_key: Option<K> = None;
+        let mut cursor = self.cursor_front_mut();
+        while let Some(val) = cursor.current() {
+            let k = key(val);
+            if prev_key.as_ref() == Some(&k) {
+                cursor.remove_current(); // stays put, so the next value is checked against the same key
+            } else {
+                prev_key = Some(k);
+                cursor.move_next();
+            }
+        }
+    }
+
+    /// Returns a reference to the element at index `n`, without cloning it like `get` does.
+    ///
+    /// Walks at most `n + 1` nodes, and stops early if the list ends first.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The index of the element.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the element at index `n`.
+    /// * `None` - If `n` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.nth_ref(1), Some(&2));
+    /// assert_eq!(list.nth_ref(3), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn nth_ref(&self, n: usize) -> Option<&T> {
+        self.iter().nth(n)
+    }
+
+    /// Returns a builder that creates a list through chained calls.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedListBuilder<T>` - A builder holding an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::builder()
+    ///     .push_back(2)
+    ///     .push_back(3)
+    ///     .push_head(1)
+    ///     .build();
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    pub fn builder() -> LinkedListBuilder<T> {
+        LinkedListBuilder {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Keeps only the first `n` elements and drops the rest.
+    ///
+    /// Does nothing if `n` is greater than or equal to the length of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to keep at the head.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+    /// list.keep_first(2);
+    /// assert_eq!(format!("{}", list), "(1 -> 2)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn keep_first(&mut self, n: usize) {
+        if n >= self.len {
+            return;
+        }
+
+        let mut link = &mut self.head;
+        for _ in 0..n {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        // Dropped as a list, so the nodes are freed one at a time.
+        let rest = LinkedList {
+            len: self.len - n,
+            head: link.take(),
+        };
+        self.len = n;
+        drop(rest);
+    }
+
+    /// Keeps only the last `n` elements and drops the rest.
+    ///
+    /// Does nothing if `n` is greater than or equal to the length of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to keep at the tail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+    /// list.keep_last(2);
+    /// assert_eq!(format!("{}", list), "(4 -> 5)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn keep_last(&mut self, n: usize) {
+        if n >= self.len {
+            return;
+        }
+
+        let mut link = &mut self.head;
+        for _ in 0..self.len - n {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        let last = link.take();
+        // Dropped as a list, so the nodes are freed one at a time.
+        let front = LinkedList {
+            len: self.len - n,
+            head: mem::replace(&mut self.head, last),
+        };
+        self.len = n;
+        drop(front);
+    }
+
+    /// Returns a new list with the leading elements that satisfy the predicate.
+    ///
+    /// Stops at the first element for which `pred` returns `false`; `skip_while_list` returns the
+    /// rest.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements to take.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<T>` - Clones of the leading matching elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6, 1, 8]);
+    /// assert_eq!(format!("{}", list.take_while_list(|x| x % 2 == 0)), "(2 -> 4 -> 6)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn take_while_list<F: FnMut(&T) -> bool>(&self, mut pred: F) -> LinkedList<T> {
+        self.iter().take_while(|x| pred(x)).cloned().collect()
+    }
+
+    /// Returns a new list without the leading elements that satisfy the predicate.
+    ///
+    /// Starts at the first element for which `pred` returns `false`; `take_while_list` returns the
+    /// part before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements to skip.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<T>` - Clones of the elements from the first non-matching one on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6, 1, 8]);
+    /// assert_eq!(format!("{}", list.skip_while_list(|x| x % 2 == 0)), "(1 -> 8)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn skip_while_list<F: FnMut(&T) -> bool>(&self, mut pred: F) -> LinkedList<T> {
+        self.iter().skip_while(|x| pred(x)).cloned().collect()
+    }
+
+    /// Returns the sum of the elements, the same as `iter().cloned().sum()`.
+    ///
+    /// # Returns
+    ///
+    /// * `T` - The sum, or the zero of `T` for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// assert_eq!(list.sum(), 10);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn sum(&self) -> T
+    where
+        T: core::iter::Sum<T>,
+    {
+        self.iter().cloned().sum()
+    }
+
+    /// Returns the product of the elements, the same as `iter().cloned().product()`.
+    ///
+    /// # Returns
+    ///
+    /// * `T` - The product, or the one of `T` for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// assert_eq!(list.product(), 24);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn product(&self) -> T
+    where
+        T: core::iter::Product<T>,
+    {
+        self.iter().cloned().product()
+    }
+
+    /// Returns a new list of the running states of an accumulator, like `Iterator::scan`.
+    ///
+    /// For each element, `f` updates the state and returns the value to put in the new list.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial state.
+    /// * `f` - Updates the state with the next element and returns the output for it.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<B>` - One output per element, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// let sums = list.scan(0, |acc, x| {
+    ///     *acc += x;
+    ///     *acc
+    /// });
+    /// assert_eq!(format!("{}", sums), "(1 -> 3 -> 6 -> 10)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn scan<B, F: FnMut(&mut B, &T) -> B>(&self, init: B, mut f: F) -> LinkedList<B> {
+        let mut state = init;
+        self.iter().map(|val| f(&mut state, val)).collect()
+    }
+
+    /// Returns a uniformly random element, or `None` if the list is empty.
+    ///
+    /// Uses reservoir sampling: a single pass where the `i`-th element replaces the current pick
+    /// with probability `1 / (i + 1)`, so it does not rely on `len`.
+    ///
+    /// Only available with the `rand` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw from.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the chosen element.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// assert!(list.choose(&mut rng).is_some());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    #[cfg(feature = "rand")]
+    pub fn choose<R: rand::Rng>(&self, rng: &mut R) -> Option<&T> {
+        let mut chosen = None;
+        for (i, val) in self.iter().enumerate() {
+            if rng.gen_range(0..=i) == 0 {
+                chosen = Some(val);
+            }
+        }
+        chosen
+    }
+
+    /// Joins several lists end to end, in order, into one list.
+    ///
+    /// The nodes are relinked, not copied. Like `splice` at the end of a list, each list is hung
+    /// off the end of the result; the end is kept between lists, so every node is visited once.
+    ///
+    /// # Arguments
+    ///
+    /// * `lists` - The lists to join, consumed.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<T>` - All elements of the lists, in order. Empty if `lists` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let lists = vec![LinkedList::from_iter(vec![1, 2]), LinkedList::from_iter(vec![3])];
+    /// let list = LinkedList::concat_all(lists);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn concat_all(lists: Vec<LinkedList<T>>) -> LinkedList<T> {
+        let mut joined = LinkedList::new();
+        let mut link = &mut joined.head;
+        for mut list in lists {
+            joined.len += list.len;
+            *link = list.head.take();
+            while let Some(node) = link {
+                link = &mut node.next;
+            }
+        }
+        joined
+    }
+
+    /// Merges two lists by taking elements from each in turn, starting with `self`.
+    ///
+    /// The result is `self[0], other[0], self[1], other[1], ...`; once one list runs out, the rest
+    /// of the other is appended as is. The nodes are relinked, not copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list providing every second element.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedList<T>` - The interleaved list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5, 6]);
+    /// let b: LinkedList<i32> = LinkedList::from_iter(vec![2, 4]);
+    /// assert_eq!(format!("{}", a.interleave(b)), "(1 -> 2 -> 3 -> 4 -> 5 -> 6)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn interleave(mut self, mut other: LinkedList<T>) -> LinkedList<T> {
+        let mut interleaved = LinkedList::new();
+        interleaved.len = self.len + other.len;
+
+        let (mut from, mut next) = (self.head.take(), other.head.take());
+        let mut link = &mut interleaved.head;
+        while let Some(mut node) = from.take() {
+            from = node.next.take();
+            link = &mut link.insert(node).next;
+            mem::swap(&mut from, &mut next); // the other list gives the next element
+        }
+        // `from` ran out, whatever is left of the other list goes at the end
+        *link = next;
+
+        interleaved
+    }
+
+    /// Returns the index of the first element for which the predicate is `false`.
+    ///
+    /// Like `slice::partition_point`, the list must be partitioned by `pred`: all elements for
+    /// which it returns `true` come before all those for which it returns `false`. The list has no
+    /// random access, so this is a linear scan from the head: O(n), not O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements of the first part.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The length of the first part, `len` if `pred` holds for every element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(list.partition_point(|&x| x < 3), 2);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().position(|x| !pred(x)).unwrap_or(self.len)
+    }
+
+    /// Returns an iterator over references to the elements that can look ahead.
+    ///
+    /// # Returns
+    ///
+    /// * `LinkedListPeekableIterator<'_, T>` - An iterator with `peek` and `peek_ahead`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let mut iter = list.peekable_iter();
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// assert_eq!(iter.peek_ahead(2), Some(&3));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.peek(), Some(&2));
+    /// ```
+    pub fn peekable_iter(&self) -> LinkedListPeekableIterator<'_, T> {
+        LinkedListPeekableIterator { iter: self.iter() }
+    }
+
+    /// Checks whether the list reads the same from both ends.
+    ///
+    /// The list cannot be walked backwards, so references to the elements are collected into a
+    /// temporary `Vec` first, then compared pairwise from the ends inward.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the list is a palindrome. Empty and single-element lists are.
+    /// * `false` - Otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1]);
+    /// assert!(list.is_palindrome());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    ///
+    pub fn is_palindrome(&self) -> bool {
+        let refs: Vec<&T> = self.iter().collect();
+        let half = refs.len() / 2;
+        refs.iter().take(half).eq(refs.iter().rev().take(half))
+    }
+
+    /// Removes consecutive elements that `same` considers duplicates, like `Vec::dedup_by`.
+    ///
+    /// `same(a, b)` is called with an element `a` and the element `b` kept before it; if it returns
+    /// `true`, `a` is removed. The first element of each run is the one that stays.
+    ///
+    /// # Arguments
+    ///
+    /// * `same` - Returns `true` if the element is a duplicate of the one kept before it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = LinkedList::from_iter(vec!["a", "A", "b", "a"]);
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(format!("{}", list), "(a -> b -> a)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) {
+        let mut kept = match self.head.as_mut() {
+            Some(node) => node,
+            None => return,
+        };
+        while let Some(mut next) = kept.next.take() {
+            if same(&next.value, &kept.value) {
+                kept.next = next.next.take(); // `next` is dropped here
+                self.len -= 1;
+            } else {
+                kept = kept.next.insert(next);
+            }
+        }
+    }
+
+    /// Returns the index of the first element that is not less than `val`.
+    ///
+    /// The list must be sorted in ascending order. This is `partition_point(|x| x < val)`, a
+    /// linear scan, not a binary search.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The first index where `val` could be inserted keeping the list sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 2, 2, 3]);
+    /// assert_eq!(list.lower_bound(&2), 1);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn lower_bound(&self, val: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|x| x < val)
+    }
+
+    /// Returns the index of the first element that is greater than `val`.
+    ///
+    /// The list must be sorted in ascending order. This is `partition_point(|x| x <= val)`, a
+    /// linear scan, not a binary search.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to look for.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The last index where `val` could be inserted keeping the list sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 2, 2, 3]);
+    /// assert_eq!(list.upper_bound(&2), 4);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn upper_bound(&self, val: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.partition_point(|x| x <= val)
+    }
+
+    /// Folds the elements from head to tail, stopping at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator.
+    /// * `f` - Combines the accumulator with an element, or returns an error to stop.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(B)` - The final accumulator if `f` never failed.
+    /// * `Err(E)` - The first error returned by `f`; the remaining elements are not visited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u8> = LinkedList::from_iter(vec![100, 100, 100]);
+    /// let sum = list.try_fold(0u8, |acc, &x| acc.checked_add(x).ok_or("overflow"));
+    /// assert_eq!(sum, Err("overflow"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn try_fold<B, E, F: FnMut(B, &T) -> Result<B, E>>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> Result<B, E> {
+        let mut acc = init;
+        for val in self.iter() {
+            acc = f(acc, val)?;
+        }
+        Ok(acc)
+    }
+
+    /// Checks if every element satisfies the predicate, stopping at the first one that does not.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If `pred` holds for every element, or the list is empty.
+    /// * `false` - Otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6]);
+    /// assert!(list.all(|&x| x % 2 == 0));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn all<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.iter().all(pred)
+    }
+
+    /// Checks if any element satisfies the predicate, stopping at the first one that does.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If `pred` holds for at least one element.
+    /// * `false` - Otherwise, including when the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert!(list.any(|&x| x > 2));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn any<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.iter().any(pred)
+    }
+
+    /// Returns a reference to the element with the largest key.
+    ///
+    /// If several elements have an equally maximal key, the first one is returned, like
+    /// `max_elem` (unlike `Iterator::max_by_key`, which returns the last one). `f` is called once
+    /// per element.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Computes the key of an element.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the element with the largest key.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<&str> = LinkedList::from_iter(vec!["a", "ccc", "bb"]);
+    /// assert_eq!(list.max_by_key(|s| s.len()), Some(&"ccc"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        let mut iter = self.iter();
+        let mut res = iter.next()?;
+        let mut res_key = f(res);
+
+        for val in iter {
+            let key = f(val);
+            if key > res_key {
+                res = val;
+                res_key = key;
+            }
+        }
+
+        Some(res)
+    }
+
+    /// Returns a reference to the element with the smallest key.
+    ///
+    /// If several elements have an equally minimal key, the first one is returned, like `min_elem`.
+    /// `f` is called once per element.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Computes the key of an element.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the element with the smallest key.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<&str> = LinkedList::from_iter(vec!["ccc", "a", "bb"]);
+    /// assert_eq!(list.min_by_key(|s| s.len()), Some(&"a"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    ///
+    pub fn min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F) -> Option<&T> {
+        let mut iter = self.iter();
+        let mut res = iter.next()?;
+        let mut res_key = f(res);
+
+        for val in iter {
+            let key = f(val);
+            if key < res_key {
+                res = val;
+                res_key = key;
+            }
+        }
+
+        Some(res)
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        LinkedList { len: 0, head: None }
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        LinkedListBorrowIterator::new(self.head.as_ref())
+            .cloned()
+            .collect()
+    }
+
+    /// Overwrites the values of the existing nodes in place, then appends or drops nodes to match
+    /// the length of `source`, so cloning into a list that is already about the right size
+    /// allocates little or nothing.
+    fn clone_from(&mut self, source: &Self) {
+        let mut src = LinkedListBorrowIterator::new(source.head.as_ref());
+        let mut link = &mut self.head;
+        while link.is_some() {
+            match src.next() {
+                Some(val) => {
+                    let node = link.as_mut().unwrap();
+                    node.value.clone_from(val);
+                    link = &mut node.next;
+                }
+                None => break,
+            }
+        }
+
+        // Drop the nodes `source` has no values for
+        drop_nodes(link.take());
+
+        // Append the values the existing nodes had no room for
+        for val in src {
+            link = &mut link
+                .insert(Box::new(LinkedListNode::new(val.clone(), None)))
+                .next;
+        }
+
+        self.len = source.len;
+    }
+}
+
+/// Builds a list from an iterator, in iteration order.
+///
+/// Through the standard library's `FromIterator` impl for `Result`, this also collects an iterator
+/// of `Result<T, E>` into a `Result<LinkedList<T>, E>`, stopping at the first `Err`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hym::box_linked_list::LinkedList;
+///
+/// let ok: Result<LinkedList<i32>, String> = vec![Ok(1), Ok(2)].into_iter().collect();
+/// assert_eq!(format!("{}", ok.unwrap()), "(1 -> 2)");
+///
+/// let err: Result<LinkedList<i32>, String> =
+///     vec![Ok(1), Err("bad".to_string()), Ok(3)].into_iter().collect();
+/// assert_eq!(err, Err("bad".to_string()));
+/// ```
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::default();
+        // Keep hold of the last link so each value is appended in O(1) instead of walking from the head.
+        let mut tail = &mut list.head;
+        for val in iter {
+            tail = &mut tail.insert(Box::new(LinkedListNode::new(val, None))).next;
+            list.len += 1;
+        }
+        list
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && LinkedListBorrowIterator::new(self.head.as_ref())
+                .eq(LinkedListBorrowIterator::new(other.head.as_ref()))
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+/// Lists compare equal to slices, arrays and `Vec`s with the same elements in the same order.
+impl<T: PartialEq> PartialEq<[T]> for LinkedList<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len()
+            && LinkedListBorrowIterator::new(self.head.as_ref()).eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for LinkedList<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for LinkedList<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for LinkedList<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+/// Lists are ordered lexicographically, like `Vec` and slices: elements are compared pairwise and
+/// a list that is a strict prefix of another is the smaller one.
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        LinkedListBorrowIterator::new(self.head.as_ref())
+            .partial_cmp(LinkedListBorrowIterator::new(other.head.as_ref()))
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        LinkedListBorrowIterator::new(self.head.as_ref())
+            .cmp(LinkedListBorrowIterator::new(other.head.as_ref()))
+    }
+}
+
+/// Hashes the length, then every element from head to tail. The NonNull list hashes the same way,
+/// so equal contents hash equally in either list.
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for item in LinkedListBorrowIterator::new(self.head.as_ref()) {
+            item.hash(state);
+        }
+    }
+}
+
+/// Drops a chain of nodes, unlinking them one at a time.
+///
+/// Dropping the first `Box` directly would drop its `next`, and so on: one stack frame per node,
+/// which overflows the stack on long lists. Taking each `next` out before the node is dropped
+/// keeps the stack depth constant.
+fn drop_nodes<T>(mut curr: Option<Box<LinkedListNode<T>>>) {
+    while let Some(mut node) = curr {
+        curr = node.next.take();
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    /// Unlinks the nodes one at a time, see `drop_nodes`.
+    fn drop(&mut self) {
+        drop_nodes(self.head.take());
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    /// Formats the elements as a list, `LinkedList [1, 2, 3]`, rather than the nested nodes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LinkedList ")?;
+        f.debug_list()
+            .entries(LinkedListBorrowIterator::new(self.head.as_ref()))
+            .finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.len == 0 {
+            return write!(f, "()"); // Empty list
+        }
+
+        write!(f, "(")?;
+
+        let mut curr = self.head.as_ref().unwrap();
+        let mut first = true;
+
+        for _ in 0..self.len {
+            if !first {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", curr.value)?;
+            first = false;
+            if curr.next.is_some() {
+                curr = curr.next.as_ref().unwrap();
+            }
+        }
+
+        write!(f, ")")?;
+        Ok(())
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = LinkedListIterator<T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        LinkedListIterator::new(self.head.take())
+    }
+}
+
+/// Displays a `LinkedList<T>` with a custom separator and brackets, see `LinkedList::display_with`.
+pub struct DisplayWith<'a, T> {
+    list: &'a LinkedList<T>,
+    sep: &'a str,
+    open: &'a str,
+    close: &'a str,
+}
+
+impl<'a, T> DisplayWith<'a, T> {
+    /// Wraps the output in `open` and `close`.
+    pub fn brackets(self, open: &'a str, close: &'a str) -> Self {
+        DisplayWith {
+            open,
+            close,
+            ..self
+        }
+    }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for DisplayWith<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.open)?;
+        let mut first = true;
+        for val in LinkedListBorrowIterator::new(self.list.head.as_ref()) {
+            if !first {
+                write!(f, "{}", self.sep)?;
+            }
+            write!(f, "{}", val)?;
+            first = false;
+        }
+        write!(f, "{}", self.close)
+    }
+}
+
+/// Builds a `LinkedList` through chained calls, see `LinkedList::builder`.
+///
+/// Each method applies the `LinkedList` method of the same name and hands the builder back.
+#[derive(Debug)]
+pub struct LinkedListBuilder<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> LinkedListBuilder<T>
+where
+    T: cmp::PartialEq + Clone,
+{
+    /// Adds a value to the end of the list being built.
+    pub fn push_back(mut self, val: T) -> Self {
+        self.list.push_back(val);
+        self
+    }
+
+    /// Adds a value to the beginning of the list being built.
+    pub fn push_head(mut self, val: T) -> Self {
+        self.list.push_head(val);
+        self
+    }
+
+    /// Inserts a value at a specific index of the list being built.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the index is out of range(valid: 0 <= at <= len).
+    pub fn insert(mut self, val: T, at: usize) -> Self {
+        self.list
+            .insert(val, at)
+            .expect("insertion index out of range");
+        self
+    }
+
+    /// Returns the built list.
+    pub fn build(self) -> LinkedList<T> {
+        self.list
+    }
+}
+
+/// A cursor over a `LinkedList<T>` that can move forward and edit the list at its position.
+///
+/// # Explanation
+///
+/// The cursor holds the link (`head` or some node's `next`) that points to the current node, so the
+/// current node can be unlinked and new nodes can be linked in without walking from the head. Once
+/// the cursor moves past the last element it sits on the end of the list, where `current` is `None`.
+pub struct CursorMut<'a, T> {
+    link: Option<&'a mut Option<Box<LinkedListNode<T>>>>, // The link pointing to the current node, always `Some`.
+    len: &'a mut usize,                                   // The length of the list being edited.
+    index: usize,                                         // The index of the current node.
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    fn new(head: &'a mut Option<Box<LinkedListNode<T>>>, len: &'a mut usize) -> CursorMut<'a, T> {
+        CursorMut {
+            link: Some(head),
+            len,
+            index: 0,
+        }
+    }
+
+    /// Returns the index of the current element, or the length of the list if the cursor is at the end.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the current element, or `None` if the cursor is at the end of the list.
+    pub fn current(&self) -> Option<&T> {
+        self.link.as_ref().unwrap().as_ref().map(|node| &node.value)
+    }
+
+    /// Returns a mutable reference to the current element, or `None` if the cursor is at the end.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.link
+            .as_mut()
+            .unwrap()
+            .as_mut()
+            .map(|node| &mut node.value)
+    }
+
+    /// Moves the cursor to the next element. At the end of the list this does nothing.
+    pub fn move_next(&mut self) {
+        let link = self.link.take().unwrap();
+        if link.is_some() {
+            self.link = Some(&mut link.as_mut().unwrap().next);
+            self.index += 1;
+        } else {
+            self.link = Some(link);
+        }
+    }
+
+    /// Inserts a new element right after the current one. The cursor does not move.
+    ///
+    /// If the cursor is at the end of the list, the value is appended and the cursor stays at the end.
+    pub fn insert_after(&mut self, val: T) {
+        let link = self.link.as_mut().unwrap();
+        *self.len += 1;
+        match link.as_mut() {
+            Some(node) => node.insert(val),
+            None => {
+                **link = Some(Box::new(LinkedListNode::new(val, None)));
+                self.move_next();
+            }
+        }
+    }
+
+    /// Removes the current element and returns it. The cursor moves onto the element that followed it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The removed value.
+    /// * `None` - If the cursor is at the end of the list.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let link = self.link.as_mut().unwrap();
+        let node = link.take()?;
+        **link = node.next;
+        *self.len -= 1;
+        Some(node.value)
+    }
+}
+
+/// Iterator for LinkedList<T>
+pub struct LinkedListIterator<T> {
+    current: Option<Box<LinkedListNode<T>>>,
+}
+
+impl<T> LinkedListIterator<T> {
+    pub fn new(head: Option<Box<LinkedListNode<T>>>) -> LinkedListIterator<T> {
+        LinkedListIterator { current: head }
+    }
+}
+
+impl<T> Iterator for LinkedListIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.current.take() {
+            self.current = node.next;
+            Some(node.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Drop for LinkedListIterator<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {} // free the remaining nodes one at a time, like `LinkedList`'s drop
+    }
+}
+
+/// Borrow iterators for LinkedList<T>
+pub struct LinkedListBorrowIterator<'a, T> {
+    current: Option<&'a LinkedListNode<T>>,
+}
+
+impl<'a, T> LinkedListBorrowIterator<'a, T> {
+    #[allow(clippy::borrowed_box)] // Kept as `&Box` so callers can pass `head.as_ref()`.
+    pub fn new(head: Option<&'a Box<LinkedListNode<T>>>) -> LinkedListBorrowIterator<'a, T> {
+        LinkedListBorrowIterator {
+            current: head.map(|node| &**node),
+        }
+    }
+}
+
+// Not derived: the derive would require `T: Clone`, but only the reference is copied.
+impl<'a, T> Clone for LinkedListBorrowIterator<'a, T> {
+    fn clone(&self) -> Self {
+        LinkedListBorrowIterator {
+            current: self.current,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LinkedListBorrowIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.current.take() {
+            self.current = node.next.as_deref();
+            Some(&node.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Borrowed iterator for `LinkedList<T>` that can look ahead without advancing.
+///
+/// Looking ahead walks a copy of the inner iterator, which is just a node reference, so nothing has
+/// to be buffered and the position of the iterator itself never moves.
+pub struct LinkedListPeekableIterator<'a, T> {
+    iter: LinkedListBorrowIterator<'a, T>,
+}
+
+impl<'a, T> LinkedListPeekableIterator<'a, T> {
+    /// Returns the element the next call to `next` will yield, without advancing.
+    pub fn peek(&self) -> Option<&'a T> {
+        self.peek_ahead(0)
+    }
+
+    /// Returns the element `n` places after the next one (`peek_ahead(0)` is `peek`), without
+    /// advancing. Walks `n + 1` nodes.
+    pub fn peek_ahead(&self, n: usize) -> Option<&'a T> {
+        self.iter.clone().nth(n)
+    }
+}
+
+impl<'a, T> Iterator for LinkedListPeekableIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// Borrow Mut iter for LinkedList<T>
+pub struct LinkedListBorrowMutIterator<'a, T> {
+    current: Option<&'a mut Box<LinkedListNode<T>>>,
+}
+
+impl<'a, T> LinkedListBorrowMutIterator<'a, T> {
+    pub fn new(head: Option<&'a mut Box<LinkedListNode<T>>>) -> LinkedListBorrowMutIterator<'a, T> {
+        LinkedListBorrowMutIterator { current: head }
+    }
+}
+
+impl<'a, T> Iterator for LinkedListBorrowMutIterator<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.current.take() {
+            self.current = node.next.as_mut();
+            Some(&mut node.value)
+        } else {
+            None
+        }
+    }
+}
+
+// Unit Test for LinkedList
 #[cfg(test)]
 mod tests {
     use std::vec;
 
-    use super::*;
+    use super::*;
+
+    #[test]
+    fn test_push_head() {
+        // Test adding elements to the head of the list
+        let mut list = LinkedList::new();
+        list.push_head(1); // Add 1 to the head
+        assert_eq!(list.len(), 1); // List should contain 1 element
+        assert_eq!(list.get(0), Some(1)); // First element should be 1
+
+        list.push_head(2); // Add 2 to the head
+        assert_eq!(list.len(), 2); // List should now contain 2 elements
+        assert_eq!(list.get(0), Some(2)); // First element should be 2
+        assert_eq!(list.get(1), Some(1)); // Second element should be 1
+    }
+
+    #[test]
+    fn test_push_back() {
+        // Test adding elements to the back of the list
+        let mut list = LinkedList::new();
+        list.push_back(1); // Add 1 to the back
+        assert_eq!(list.len(), 1); // List should contain 1 element
+        assert_eq!(list.get(0), Some(1)); // First element should be 1
+
+        list.push_back(2); // Add 2 to the back
+        assert_eq!(list.len(), 2); // List should contain 2 elements
+        assert_eq!(list.get(1), Some(2)); // Second element should be 2
+    }
+
+    #[test]
+    fn test_pop_head() {
+        // Test removing elements from the head of the list
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_head(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+
+        list.push_head(1); // Add 1 to the head
+        list.push_head(2); // Add 2 to the head
+        assert_eq!(list.pop_head(), Ok(2)); // Pop should return 2 (head element)
+        assert_eq!(list.len(), 1); // List should now contain 1 element
+        assert_eq!(list.pop_head(), Ok(1)); // Pop should return 1
+        assert_eq!(list.len(), 0); // List should be empty
+        assert_eq!(list.pop_head(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+    }
+
+    #[test]
+    fn test_pop_back() {
+        // Test removing elements from the back of the list
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_back(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+
+        list.push_back(1); // Add 1 to the back
+        list.push_back(2); // Add 2 to the back
+        list.push_back(3); // Add 3 to the back
+        assert_eq!(list.pop_back(), Ok(3)); // Pop should return 3 (last element)
+        assert_eq!(list.len(), 2); // List should now contain 2 elements
+        assert_eq!(list.pop_back(), Ok(2)); // Pop should return 2
+        assert_eq!(list.len(), 1); // List should now contain 1 element
+        assert_eq!(list.pop_back(), Ok(1)); // Pop should return 1
+        assert_eq!(list.len(), 0); // List should be empty
+        assert_eq!(list.pop_back(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+    }
+
+    #[test]
+    fn test_insert() {
+        // Test inserting elements at a specific position
+        let mut list = LinkedList::new();
+        assert_eq!(list.insert(1, 1), Err(LinkedListError::InsertOutOfRange)); // Inserting out of range
+
+        list.push_back(1); // Add 1 to the back
+        list.push_back(3); // Add 3 to the back
+        assert_eq!(list.insert(2, 1), Ok(())); // Insert 2 at position 1
+        assert_eq!(list.len(), 3); // List should contain 3 elements
+        assert_eq!(list.get(1), Some(2)); // Element at position 1 should be 2
+
+        assert_eq!(list.insert(4, 3), Ok(())); // Insert 4 at position 3
+        assert_eq!(list.len(), 4); // List should contain 4 elements
+        assert_eq!(list.get(3), Some(4)); // Element at position 3 should be 4
+
+        assert_eq!(list.insert(0, 0), Ok(())); // Insert 0 at position 0
+        assert_eq!(list.len(), 5); // List should contain 5 elements
+        assert_eq!(list.get(0), Some(0)); // Element at position 0 should be 0
+
+        // Attempt to insert out of range
+        assert_eq!(list.insert(5, 6), Err(LinkedListError::InsertOutOfRange)); // Inserting out of range should return an error
+    }
+
+    #[test]
+    fn test_remove() {
+        // Test removing elements at a specific position
+        let mut list = LinkedList::new();
+        assert_eq!(list.remove(0), Err(LinkedListError::RemoveFromEmptyList)); // Remove from an empty list should return an error
+
+        list.push_back(1); // Add 1 to the back
+        list.push_back(2); // Add 2 to the back
+        list.push_back(3); // Add 3 to the back
+        assert_eq!(list.remove(1), Ok(2)); // Remove element at position 1 (value 2)
+        assert_eq!(list.len(), 2); // List should now contain 2 elements
+        assert_eq!(list.get(1), Some(3)); // Element at position 1 should be 3
+
+        assert_eq!(list.remove(0), Ok(1)); // Remove element at position 0 (value 1)
+        assert_eq!(list.len(), 1); // List should now contain 1 element
+        assert_eq!(list.get(0), Some(3)); // Element at position 0 should be 3
+
+        assert_eq!(list.remove(0), Ok(3)); // Remove last element (value 3)
+        assert_eq!(list.len(), 0); // List should be empty
+        assert_eq!(list.remove(0), Err(LinkedListError::RemoveFromEmptyList)); // Remove from an empty list should return an error
+    }
+
+    #[test]
+    fn test_val2ix() {
+        // Test finding indices of a specific value
+        let mut list = LinkedList::new();
+        assert_eq!(list.val2ix(&1), vec![]); // No elements in the list
+
+        list.push_back(1); // Add 1 to the back
+        list.push_back(2); // Add 2 to the back
+        list.push_back(3); // Add 3 to the back
+        list.push_back(2); // Add another 2 to the back
+
+        assert_eq!(list.val2ix(&1), vec![0]); // 1 is at index 0
+        assert_eq!(list.val2ix(&2), vec![1, 3]); // 2 is at indices 1 and 3
+        assert_eq!(list.val2ix(&3), vec![2]); // 3 is at index 2
+        assert_eq!(list.val2ix(&4), vec![]); // No 4 in the list
+    }
+
+    #[test]
+    fn test_ix2val() {
+        // Test accessing value by index
+        let mut list = LinkedList::new();
+        list.push_back(10); // Add 10 to the back
+        list.push_back(20); // Add 20 to the back
+        list.push_back(30); // Add 30 to the back
+
+        assert_eq!(list.ix2val(0), Some(10)); // Element at index 0 should be 10
+        assert_eq!(list.ix2val(1), Some(20)); // Element at index 1 should be 20
+        assert_eq!(list.ix2val(2), Some(30)); // Element at index 2 should be 30
+        assert_eq!(list.ix2val(3), None); // No element at index 3
+    }
+
+    #[test]
+    fn test_get() {
+        // Test retrieving element at a specific index
+        let mut list = LinkedList::new();
+        list.push_back(100); // Add 100 to the back
+        list.push_back(200); // Add 200 to the back
+
+        assert_eq!(list.get(0), Some(100)); // Element at index 0 should be 100
+        assert_eq!(list.get(1), Some(200)); // Element at index 1 should be 200
+        assert_eq!(list.get(2), None); // No element at index 2
+    }
+
+    #[test]
+    fn test_len() {
+        // Test the length of the list
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.len(), 0); // Empty list
+
+        list.push_head(1); // Add 1 to the head
+        assert_eq!(list.len(), 1); // List should contain 1 element
+
+        list.push_back(2); // Add 2 to the back
+        assert_eq!(list.len(), 2); // List should contain 2 elements
+
+        list.pop_head().unwrap(); // Remove from head
+        assert_eq!(list.len(), 1); // List should contain 1 element
+
+        list.pop_back().unwrap(); // Remove from back
+        assert_eq!(list.len(), 0); // List should be empty
+    }
+
+    #[test]
+    fn test_display() {
+        // Test the display of the list
+        let mut list = LinkedList::new();
+        assert_eq!(format!("{}", list), "()"); // Empty list
+
+        list.push_back(1); // Add 1 to the back
+        assert_eq!(format!("{}", list), "(1)");
+
+        list.push_back(2); // Add 2 to the back
+        list.push_back(3); // Add 3 to the back
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+
+        list.pop_head().unwrap(); // Remove from head
+        assert_eq!(format!("{}", list), "(2 -> 3)");
+
+        list.pop_back().unwrap(); // Remove from back
+        assert_eq!(format!("{}", list), "(2)");
+    }
+
+    #[test]
+    fn test_clone() {
+        // Test cloning the list
+        let mut list = LinkedList::new();
+        list.push_back(1); // Add 1 to the back
+        list.push_back(2); // Add 2 to the back
+        list.push_back(3); // Add 3 to the back
+
+        let cloned_list = list.clone(); // Clone the list
+        assert_eq!(cloned_list.len(), 3); // Cloned list should contain 3 elements
+        assert_eq!(cloned_list.get(0), Some(1)); // First element should be 1
+        assert_eq!(cloned_list.get(1), Some(2)); // Second element should be 2
+        assert_eq!(cloned_list.get(2), Some(3)); // Third element should be 3
+
+        // Ensure modifying original list does not affect cloned list
+        list.pop_back().unwrap(); // Modify original list
+        assert_eq!(list.len(), 2); // Original list should have 2 elements
+        assert_eq!(cloned_list.len(), 3); // Cloned list should still have 3 elements
+    }
+
+    #[test]
+    fn test_insert_remove_multiple() {
+        // Test inserting and removing multiple elements
+        let mut list = LinkedList::new();
+        list.push_back(1); // List: 1
+        list.push_back(3); // List: 1 -> 3
+        list.insert(2, 1).unwrap(); // List: 1 -> 2 -> 3
+        list.insert(4, 3).unwrap(); // List: 1 -> 2 -> 3 -> 4
+        list.insert(0, 0).unwrap(); // List: 0 -> 1 -> 2 -> 3 -> 4
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{}", list), "(0 -> 1 -> 2 -> 3 -> 4)");
+
+        // Remove elements from various positions
+        assert_eq!(list.remove(2), Ok(2)); // List: 0 -> 1 -> 3 -> 4
+        assert_eq!(list.remove(0), Ok(0)); // List: 1 -> 3 -> 4
+        assert_eq!(list.remove(2), Ok(4)); // List: 1 -> 3
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(format!("{}", list), "(1 -> 3)");
+    }
+
+    #[test]
+    fn test_clean() {
+        // Test cleaning the list
+        let mut list = LinkedList::new();
+
+        // Test clean on an empty list
+        list.clean();
+        assert_eq!(list.len(), 0);
+        assert_eq!(format!("{}", list), "()");
+
+        // Test clean on a list with elements
+        list.push_back(1); // Add 1 to the back
+        list.push_back(2); // Add 2 to the back
+        list.push_back(3); // Add 3 to the back
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+
+        // Call clean and ensure the list is empty
+        list.clean();
+        assert_eq!(list.len(), 0);
+        assert_eq!(format!("{}", list), "()");
+    }
+
+    #[test]
+    #[allow(clippy::useless_conversion)]
+    fn test_from_iter() {
+        // Test creating a list from a vector
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![]);
+        assert_eq!(list.len(), 0); // Empty list
+        assert_eq!(format!("{}", list), "()");
+
+        let list = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3); // List should contain 3 elements
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+
+        let list = LinkedList::from_iter(vec![1, 1, 1, 1]);
+        assert_eq!(list.len(), 4); // List should contain 4 elements
+        assert_eq!(format!("{}", list), "(1 -> 1 -> 1 -> 1)");
+
+        let list = LinkedList::from_iter(vec![1, 1, 1, 1].into_iter());
+        assert_eq!(list.len(), 4); // List should contain 4 elements
+        assert_eq!(format!("{}", list), "(1 -> 1 -> 1 -> 1)");
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5, 6]);
+
+        let it = list.into_iter(); // list is moved
+
+        let vec = it.collect::<Vec<i32>>();
+
+        assert_eq!(vec, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+        list.push_back(1);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        let mut iter = list.iter(); // create an borrowed iterator for linked list
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        let mut iter = list.iter_mut(); // create a mutable borrowed iterator for linked list
+
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), Some(&mut 4));
+        assert_eq!(iter.next(), Some(&mut 5));
+        assert_eq!(iter.next(), None);
+
+        for val in list.iter_mut() {
+            *val *= *val;
+        }
+
+        assert_eq!(format!("{}", list), "(1 -> 4 -> 9 -> 16 -> 25)");
+    }
+
+    #[test]
+    fn test_min_max() {
+        // Called on an owned list, where `min`/`max` would resolve to `Ord::min`/`Ord::max`
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(list.min_elem(), Some(&1));
+        assert_eq!(list.max_elem(), Some(&9));
+
+        // Single element is both the min and the max
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![42]);
+        assert_eq!(list.min_elem(), Some(&42));
+        assert_eq!(list.max_elem(), Some(&42));
+
+        // Empty list has neither
+        let list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.min_elem(), None);
+        assert_eq!(list.max_elem(), None);
+    }
+
+    #[test]
+    fn test_min_max_ties() {
+        // On ties the first occurrence is returned
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![2, 1, 3, 1, 3]);
+        assert!(std::ptr::eq(
+            list.min_elem().unwrap(),
+            list.iter().nth(1).unwrap()
+        ));
+        assert!(std::ptr::eq(
+            list.max_elem().unwrap(),
+            list.iter().nth(2).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_eq() {
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let c: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        assert_eq!(a, b);
+        assert_ne!(a, c); // Different lengths are never equal
+        assert_eq!(LinkedList::<i32>::new(), LinkedList::new());
+    }
+
+    #[test]
+    fn test_ord() {
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![1, 3]);
+        let c: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert!(a < b); // Decided by the first differing element
+        assert!(a < c); // A strict prefix is smaller
+        assert!(LinkedList::new() < a); // The empty list is the smallest
+        assert_eq!(a.cmp(&a.clone()), cmp::Ordering::Equal);
+
+        let mut lists = vec![c.clone(), b.clone(), a.clone()];
+        lists.sort();
+        assert_eq!(lists, vec![a, c, b]);
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.current(), Some(&1));
+
+        // Walk to the middle
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), 2);
+        assert_eq!(cursor.current(), Some(&3));
+
+        cursor.insert_after(30); // List: 1 -> 2 -> 3 -> 30 -> 4 -> 5
+        assert_eq!(cursor.current(), Some(&3)); // Cursor stays on 3
+        assert_eq!(cursor.remove_current(), Some(3)); // List: 1 -> 2 -> 30 -> 4 -> 5
+        assert_eq!(cursor.current(), Some(&30)); // Cursor moves onto the next element
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 30 -> 4 -> 5)");
+    }
+
+    #[test]
+    fn test_cursor_ends() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None); // Nothing to remove at the end
+        cursor.insert_after(1); // Appends and stays at the end
+        cursor.insert_after(2);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(list.len(), 2);
+        assert_eq!(format!("{}", list), "(1 -> 2)");
+
+        // Remove the head, then walk past the end
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some(1));
+        cursor.move_next();
+        cursor.move_next(); // Moving at the end does nothing
+        assert_eq!(cursor.index(), 1);
+        cursor.insert_after(3);
+        assert_eq!(list.len(), 2);
+        assert_eq!(format!("{}", list), "(2 -> 3)");
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.insert_sorted(3), 0); // Insert into an empty list
+        assert_eq!(list.insert_sorted(1), 0); // Insert at the front
+        assert_eq!(list.insert_sorted(5), 2); // Insert at the back
+        assert_eq!(list.insert_sorted(4), 2); // Insert in the middle
+        assert_eq!(list.len(), 4);
+        assert_eq!(format!("{}", list), "(1 -> 3 -> 4 -> 5)");
+    }
+
+    #[test]
+    fn test_insert_sorted_stable() {
+        // Ordered by the number only, the letter records insertion order
+        #[derive(Clone, Copy, Debug)]
+        struct Keyed(i32, char);
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Keyed {}
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        // Equal values go after the existing equal elements
+        let mut list = LinkedList::new();
+        assert_eq!(list.insert_sorted(Keyed(2, 'a')), 0);
+        assert_eq!(list.insert_sorted(Keyed(1, 'a')), 0);
+        assert_eq!(list.insert_sorted(Keyed(2, 'b')), 2);
+        assert_eq!(list.insert_sorted(Keyed(1, 'b')), 1);
+        assert_eq!(list.insert_sorted(Keyed(2, 'c')), 4);
+        let tags: String = list.iter().map(|k| k.1).collect();
+        assert_eq!(tags, "ababc");
+    }
+
+    #[test]
+    fn test_first_last() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.first(), None); // Empty list has no ends
+        assert_eq!(list.last(), None);
+
+        list.push_back(1);
+        assert_eq!(list.first(), Some(&1)); // Single element is both ends
+        assert_eq!(list.last(), Some(&1));
+
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&3));
+        assert_eq!(list.len(), 3); // Borrowing does not change the list
+    }
+
+    #[test]
+    fn test_count() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.count(&1), 0); // Empty list
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(3);
+        list.push_back(1);
+        assert_eq!(list.count(&1), 3); // 1 appears three times
+        assert_eq!(list.count(&2), 1);
+        assert_eq!(list.count(&4), 0); // No 4 in the list
+        assert_eq!(list.count(&1), list.val2ix(&1).len());
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let list = LinkedList::from_slice(&[1, 2, 3]); // From an array literal
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+
+        let vals = [3, 4, 5];
+        let list = LinkedList::from_slice(&vals[1..]); // From a sub-slice
+        assert_eq!(format!("{}", list), "(4 -> 5)");
+
+        let list: LinkedList<i32> = LinkedList::from_slice(&[]); // From an empty slice
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", list), "()");
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.peek_mut(), None);
+        assert_eq!(list.first(), None);
+
+        // push_back appends after the elements pushed to the head
+        list.push_head(2);
+        list.push_back(3);
+        list.push_head(1);
+        *list.peek_mut().unwrap() *= 10;
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&10, &2, &3]);
+        assert_eq!(list.first(), Some(&10));
+    }
+
+    #[test]
+    fn test_collect_without_clone() {
+        // A type that is neither Clone nor PartialEq
+        #[derive(Debug)]
+        struct Token(u32);
+
+        let list: LinkedList<Token> = (0..5).map(Token).collect();
+        assert_eq!(list.len, 5);
+
+        // Consuming the list yields the tokens in insertion order
+        let ids = list.into_iter().map(|t| t.0).collect::<Vec<_>>();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+
+        let list: LinkedList<u32> = (1..=3).collect();
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    }
+
+    #[test]
+    fn test_extract_if() {
+        // Extract the even elements
+        let mut list: LinkedList<i32> = (1..=6).collect();
+        assert_eq!(list.extract_if(|x| x % 2 == 0), vec![2, 4, 6]);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &3, &5]);
+        assert_eq!(list.len(), 3);
+
+        // Extract nothing
+        assert_eq!(list.extract_if(|_| false), vec![]);
+        assert_eq!(format!("{}", list), "(1 -> 3 -> 5)");
+
+        // Extract everything
+        assert_eq!(list.extract_if(|_| true), vec![1, 3, 5]);
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", list), "()");
+    }
 
     #[test]
-    fn test_push_head() {
-        // Test adding elements to the head of the list
-        let mut list = LinkedList::new();
-        list.push_head(1); // Add 1 to the head
-        assert_eq!(list.len(), 1); // List should contain 1 element
-        assert_eq!(list.get(0), Some(1)); // First element should be 1
+    fn test_starts_with() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert!(list.starts_with(&[1, 2])); // Matching prefix
+        assert!(list.starts_with(&[1, 2, 3])); // The whole list
+        assert!(!list.starts_with(&[1, 3])); // Diverges at the second element
+        assert!(!list.starts_with(&[1, 2, 3, 4])); // Longer than the list
+        assert!(list.starts_with(&[])); // Empty prefix
 
-        list.push_head(2); // Add 2 to the head
-        assert_eq!(list.len(), 2); // List should now contain 2 elements
-        assert_eq!(list.get(0), Some(2)); // First element should be 2
-        assert_eq!(list.get(1), Some(1)); // Second element should be 1
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.starts_with(&[]));
+        assert!(!empty.starts_with(&[1]));
     }
 
     #[test]
-    fn test_push_back() {
-        // Test adding elements to the back of the list
+    fn test_replace() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.replace(0, 10), Ok(1)); // Replace the head
+        assert_eq!(list.replace(1, 20), Ok(2)); // Replace a middle element
+        assert_eq!(list.replace(2, 30), Ok(3)); // Replace the last element
+        assert_eq!(format!("{}", list), "(10 -> 20 -> 30)");
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.replace(3, 40), Err(LinkedListError::RemoveOutOfRange)); // Out of range
+        assert_eq!(format!("{}", list), "(10 -> 20 -> 30)");
+    }
+
+    #[test]
+    #[ignore] // slow in debug builds; run with `cargo test -- --ignored`
+    fn test_drop_long_list() {
+        // Dropping a long list, or a partly consumed iterator over one, must not recurse once per node
+        let list: LinkedList<u32> = (0..1_000_000).collect();
+        drop(list);
+
+        // Neither must clearing one, or shrinking it
+        let mut list: LinkedList<u32> = (0..1_000_000).collect();
+        list.clean();
+        assert!(list.is_empty());
+
+        let mut list: LinkedList<u32> = (0..1_000_000).collect();
+        list.resize_with(1, || 0);
+        assert_eq!(list.len(), 1);
+        list.clone_from(&LinkedList::new());
+        assert!(list.is_empty());
+
+        let mut iter = (0..1_000_000).collect::<LinkedList<u32>>().into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+    }
+
+    #[test]
+    fn test_partition() {
+        let list: LinkedList<i32> = (1..=6).collect();
+        let (even, odd) = list.partition(|x| x % 2 == 0);
+        assert_eq!(even.iter().collect::<Vec<_>>(), vec![&2, &4, &6]);
+        assert_eq!(odd.iter().collect::<Vec<_>>(), vec![&1, &3, &5]);
+        assert_eq!((even.len(), odd.len()), (3, 3));
+
+        // An empty list splits into two empty lists
+        let (yes, no) = LinkedList::<i32>::new().partition(|_| true);
+        assert!(yes.is_empty() && no.is_empty());
+    }
+
+    #[test]
+    fn test_chunks() {
+        let list: LinkedList<i32> = (1..=7).collect();
+        let chunks = list.chunks(3).collect::<Vec<_>>();
+        assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+
+        // A chunk size larger than the list yields the whole list once
+        assert_eq!(
+            list.chunks(10).collect::<Vec<_>>(),
+            vec![vec![1, 2, 3, 4, 5, 6, 7]]
+        );
+
+        // An empty list yields no chunks
+        assert_eq!(LinkedList::<i32>::new().chunks(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let _ = list.chunks(0);
+    }
+
+    #[test]
+    fn test_windows() {
+        let list: LinkedList<i32> = (1..=4).collect();
+        let windows = list.windows(2).collect::<Vec<_>>();
+        assert_eq!(windows, vec![vec![&1, &2], vec![&2, &3], vec![&3, &4]]);
+
+        // A window as long as the list yields the list once, a longer one yields nothing
+        assert_eq!(list.windows(4).count(), 1);
+        assert_eq!(list.windows(5).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_zero() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let _ = list.windows(0);
+    }
+
+    #[test]
+    fn test_rposition() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 2, 1]);
+        assert_eq!(list.rposition(|&x| x == 2), Some(3)); // Several matches, the last one wins
+        assert_eq!(list.rposition(|&x| x == 3), Some(2)); // Single match
+        assert_eq!(list.rposition(|&x| x == 4), None); // No match
+        assert_eq!(LinkedList::<i32>::new().rposition(|_| true), None);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let list: LinkedList<i32> = (1..=5).collect();
+        let (left, right) = list.split_at(2);
+        assert_eq!(left.collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(right.collect::<Vec<_>>(), vec![&3, &4, &5]);
+
+        // At or past the end, everything is in the first half
+        let (left, mut right) = list.split_at(7);
+        assert_eq!(left.count(), 5);
+        assert_eq!(right.next(), None);
+    }
+
+    #[test]
+    fn test_display_with() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(format!("{}", list.display_with(", ")), "1, 2, 3");
+        assert_eq!(
+            format!("{}", list.display_with(";").brackets("[", "]")),
+            "[1;2;3]"
+        );
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)"); // The default Display is unchanged
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(
+            format!("{}", empty.display_with(", ").brackets("<", ">")),
+            "<>"
+        );
+    }
+
+    #[test]
+    fn test_collect_results() {
+        // All Ok builds the whole list
+        let results: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+        let list = results.into_iter().collect::<Result<LinkedList<_>, _>>();
+        assert_eq!(format!("{}", list.unwrap()), "(1 -> 2 -> 3)");
+
+        // The first Err is returned and nothing after it is consumed
+        let mut consumed = 0;
+        let results = vec![Ok(1), Err("first"), Ok(3), Err("second")];
+        let list = results
+            .into_iter()
+            .inspect(|_| consumed += 1)
+            .collect::<Result<LinkedList<i32>, _>>();
+        assert_eq!(list, Err("first"));
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn test_search_sorted() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![10, 20, 30, 40]);
+        assert_eq!(list.search_sorted(&30), Ok(2)); // Found
+        assert_eq!(list.search_sorted(&25), Err(2)); // Would insert in the middle
+        assert_eq!(list.search_sorted(&5), Err(0)); // Before the front
+        assert_eq!(list.search_sorted(&45), Err(4)); // After the back
+        assert_eq!(LinkedList::<i32>::new().search_sorted(&1), Err(0));
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+        let mut iter = list.iter();
+        iter.next();
+
+        // The clone starts where the original is and then moves on its own
+        let mut clone = iter.clone();
+        assert_eq!(clone.next(), Some(&2));
+        assert_eq!(clone.next(), Some(&3));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(clone.next(), Some(&4));
+        assert_eq!(clone.next(), None);
+    }
+
+    #[test]
+    fn test_take() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let taken = list.take();
+        assert_eq!(taken.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(taken.len(), 3);
+        assert!(list.is_empty()); // Left empty
+        assert_eq!(format!("{}", list), "()");
+
+        list.push_back(4); // and still usable
+        assert_eq!(format!("{}", list), "(4)");
+    }
+
+    #[test]
+    fn test_prepend() {
+        // Onto an empty list
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.prepend(vec![4, 5]);
+        assert_eq!(format!("{}", list), "(4 -> 5)");
+
+        // Onto a non-empty list, keeping the order of the prepended items
+        list.prepend(1..=3);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+        assert_eq!(list.len(), 5);
+
+        // Nothing to prepend
+        list.prepend(vec![]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.last(), Some(&5));
+    }
+
+    #[test]
+    fn test_zip() {
+        let nums: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let words: LinkedList<&str> = LinkedList::from_iter(vec!["a", "b", "c"]);
+
+        // Equal lengths
+        let pairs = nums.zip(&words);
+        assert_eq!(
+            pairs.iter().collect::<Vec<_>>(),
+            vec![&(1, "a"), &(2, "b"), &(3, "c")]
+        );
+
+        // The longer list is cut to the length of the shorter one, whichever side it is on
+        let short: LinkedList<&str> = LinkedList::from_iter(vec!["x"]);
+        assert_eq!(nums.zip(&short).len(), 1);
+        assert_eq!(short.zip(&nums).first(), Some(&("x", 1)));
+
+        // Either side empty
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.zip(&words).is_empty());
+        assert!(words.zip(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let list: LinkedList<char> = LinkedList::from_iter(vec!['a', 'b', 'c']);
+        let indexed = list.indexed_iter().collect::<Vec<_>>();
+        assert_eq!(indexed, vec![(0, &'a'), (1, &'b'), (2, &'c')]);
+        assert_eq!(LinkedList::<char>::new().indexed_iter().next(), None);
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        assert!(LinkedList::from_iter(vec![1, 2, 3]).is_sorted()); // Sorted
+        assert!(!LinkedList::from_iter(vec![1, 3, 2]).is_sorted()); // Unsorted
+        assert!(LinkedList::from_iter(vec![2, 2, 2]).is_sorted()); // Equal elements
+        assert!(LinkedList::from_iter(vec![1]).is_sorted()); // Single element
+        assert!(LinkedList::<i32>::new().is_sorted()); // Empty
+
+        // is_sorted_by with a custom order
+        let list = LinkedList::from_iter(vec![3, 2, 2, 1]);
+        assert!(list.is_sorted_by(|a, b| a >= b));
+        assert!(!list.is_sorted_by(|a, b| a > b)); // Strict order fails on the equal pair
+
+        // It stops at the first inversion
+        let mut compared = 0;
+        LinkedList::from_iter(vec![2, 1, 0, -1]).is_sorted_by(|a, b| {
+            compared += 1;
+            a <= b
+        });
+        assert_eq!(compared, 1);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        // Matches at the head, back to back, and at the tail
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1, 1, 3, 1]);
+        assert_eq!(list.remove_all(&1), 4);
+        assert_eq!(format!("{}", list), "(2 -> 3)");
+        assert_eq!(list.len(), 2);
+
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![5, 7, 5, 9, 5]);
+        assert_eq!(list.remove_all(&5), 3); // Three occurrences
+        assert_eq!(list.remove_all(&4), 0); // Absent value
+        assert_eq!(format!("{}", list), "(7 -> 9)");
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.remove_all(&1), 0); // Empty list
+    }
+
+    #[test]
+    fn test_nth_from_end() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+        assert_eq!(list.nth_from_end(0), Some(&4)); // Last element
+        assert_eq!(list.nth_from_end(1), Some(&3));
+        assert_eq!(list.nth_from_end(3), Some(&1)); // First element
+        assert_eq!(list.nth_from_end(4), None); // Out of range
+        assert_eq!(LinkedList::<i32>::new().nth_from_end(0), None);
+    }
+
+    #[test]
+    fn test_splice() {
+        // Into the middle
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 4, 5]);
+        list.splice(1, LinkedList::from_iter(vec![2, 3])).unwrap();
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+        assert_eq!(list.len(), 5);
+
+        // At the front and at the back
+        list.splice(0, LinkedList::from_iter(vec![0])).unwrap();
+        list.splice(6, LinkedList::from_iter(vec![6, 7])).unwrap();
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            (0..=7).collect::<Vec<_>>()
+        );
+        assert_eq!(list.len(), 8);
+
+        // An empty list changes nothing, an index past the end is rejected
+        list.splice(3, LinkedList::new()).unwrap();
+        assert_eq!(list.len(), 8);
+        assert_eq!(
+            list.splice(9, LinkedList::from_iter(vec![9])),
+            Err(LinkedListError::InsertOutOfRange)
+        );
+        assert_eq!(list.last(), Some(&7));
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let mut target: LinkedList<String> = LinkedList::from_iter(vec!["x".to_string()]);
+        let first_node = target.head.as_deref().unwrap() as *const LinkedListNode<String>;
+
+        // A longer source: the existing node is reused and the rest appended
+        let long = LinkedList::from_iter(["a", "b", "c"].map(String::from));
+        target.clone_from(&long);
+        assert_eq!(target, long);
+        assert_eq!(target.len(), 3);
+        assert_eq!(target.head.as_deref().unwrap() as *const _, first_node);
+
+        // A shorter source: the surplus nodes are dropped
+        let short = LinkedList::from_iter(["d", "e"].map(String::from));
+        target.clone_from(&short);
+        assert_eq!(format!("{}", target), "(d -> e)");
+        assert_eq!(target.len(), 2);
+        target.push_back("f".to_string()); // The end of the list is still well formed
+        assert_eq!(format!("{}", target), "(d -> e -> f)");
+
+        // An empty source
+        target.clone_from(&LinkedList::new());
+        assert!(target.is_empty());
+        assert_eq!(target.clone(), LinkedList::new());
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        list.resize(5, 9); // Grow from 2 to 5
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 9 -> 9 -> 9)");
+        assert_eq!(list.len(), 5);
+
+        list.resize(2, 0); // Shrink from 5 to 2
+        assert_eq!(format!("{}", list), "(1 -> 2)");
+        assert_eq!(list.len(), 2);
+
+        list.resize(2, 0); // Same length, nothing changes
+        assert_eq!(format!("{}", list), "(1 -> 2)");
+
+        list.push_back(3); // The end of the list is still well formed
+        assert_eq!(list.last(), Some(&3));
+        list.resize(0, 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_resize_with() {
+        let mut list: LinkedList<Vec<usize>> = LinkedList::new();
+        let mut calls = 0;
+        list.resize_with(3, || {
+            calls += 1;
+            vec![calls]
+        });
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            vec![&vec![1], &vec![2], &vec![3]]
+        );
+
+        list.resize_with(1, || unreachable!()); // Shrinking never calls the closure
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_rchunks() {
+        let list: LinkedList<i32> = (1..=7).collect();
+        let chunks = list.rchunks(3).collect::<Vec<_>>();
+        assert_eq!(chunks, vec![vec![5, 6, 7], vec![2, 3, 4], vec![1]]);
+
+        // A chunk size larger than the list yields the whole list once
+        assert_eq!(
+            list.rchunks(10).collect::<Vec<_>>(),
+            vec![(1..=7).collect::<Vec<_>>()]
+        );
+
+        // An empty list yields no chunks
+        assert_eq!(LinkedList::<i32>::new().rchunks(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_rchunks_zero() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let _ = list.rchunks(0);
+    }
+
+    #[test]
+    fn test_merge() {
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6]);
+        let merged = LinkedList::merge(a, b);
+        assert_eq!(format!("{}", merged), "(1 -> 2 -> 3 -> 4 -> 5 -> 6)");
+        assert_eq!(merged.len(), 6);
+
+        // One input empty
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        let merged = LinkedList::merge(a, LinkedList::new());
+        assert_eq!(format!("{}", merged), "(1 -> 2)");
+        let merged = LinkedList::merge(LinkedList::new(), merged);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged.last(), Some(&2));
+    }
+
+    #[test]
+    fn test_merge_stable() {
+        // Ordered by key only, so equal keys show which list each element came from
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct Keyed(u32, char);
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let a = LinkedList::from_iter(vec![Keyed(1, 'a'), Keyed(1, 'a'), Keyed(2, 'a')]);
+        let b = LinkedList::from_iter(vec![Keyed(1, 'b'), Keyed(2, 'b')]);
+        let merged = LinkedList::merge(a, b);
+        let sources = merged.iter().map(|k| (k.0, k.1)).collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            vec![(1, 'a'), (1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+        );
+    }
+
+    #[test]
+    fn test_group_runs() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 1, 2, 3, 3]);
+        assert_eq!(list.group_runs(), vec![vec![1, 1], vec![2], vec![3, 3]]);
+
+        // All distinct, all equal, and a value that comes back later
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.group_runs(), vec![vec![1], vec![2], vec![3]]);
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![7, 7, 7]);
+        assert_eq!(list.group_runs(), vec![vec![7, 7, 7]]);
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1]);
+        assert_eq!(list.group_runs().len(), 3);
+
+        assert!(LinkedList::<i32>::new().group_runs().is_empty());
+    }
+
+    #[test]
+    fn test_pop_opt() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.pop_head_opt(), Some(1));
+        assert_eq!(list.pop_back_opt(), Some(3));
+        assert_eq!(list.pop_back_opt(), Some(2));
+
+        // None once the list is exhausted
+        assert_eq!(list.pop_head_opt(), None);
+        assert_eq!(list.pop_back_opt(), None);
+        assert_eq!(list.pop_head(), Err(LinkedListError::PopFromEmptyList)); // Result versions unchanged
+    }
+
+    #[test]
+    fn test_insert_many() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![10, 20]);
+        list.insert_many(1, &[11, 12, 13]).unwrap(); // Into the middle
+        assert_eq!(format!("{}", list), "(10 -> 11 -> 12 -> 13 -> 20)");
+
+        list.insert_many(0, &[7, 8, 9]).unwrap(); // At the front
+        list.insert_many(8, &[21, 22, 23]).unwrap(); // At the back
+        assert_eq!(
+            format!("{}", list),
+            "(7 -> 8 -> 9 -> 10 -> 11 -> 12 -> 13 -> 20 -> 21 -> 22 -> 23)"
+        );
+        assert_eq!(list.len(), 11);
+
+        list.insert_many(4, &[]).unwrap(); // Empty slice, nothing changes
+        assert_eq!(list.len(), 11);
+        assert_eq!(
+            list.insert_many(12, &[1]),
+            Err(LinkedListError::InsertOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_split_first_last() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+
+        let (first, rest) = list.split_first().unwrap();
+        assert_eq!(first, &1);
+        assert_eq!(rest.collect::<Vec<_>>(), vec![&2, &3]);
+
+        let (last, init) = list.split_last().unwrap();
+        assert_eq!(last, &3);
+        assert_eq!(init.collect::<Vec<_>>(), vec![&1, &2]);
+
+        // A single element leaves nothing on the other side
+        let single: LinkedList<i32> = LinkedList::from_iter(vec![4]);
+        assert_eq!(single.split_first().unwrap().1.count(), 0);
+        assert_eq!(single.split_last().unwrap().1.count(), 0);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.split_first().is_none());
+        assert!(empty.split_last().is_none());
+    }
+
+    #[test]
+    fn test_collect_into() {
         let mut list = LinkedList::new();
-        list.push_back(1); // Add 1 to the back
-        assert_eq!(list.len(), 1); // List should contain 1 element
-        assert_eq!(list.get(0), Some(1)); // First element should be 1
+        list.push_back(1);
+        list.push_back(2);
 
-        list.push_back(2); // Add 2 to the back
-        assert_eq!(list.len(), 2); // List should contain 2 elements
-        assert_eq!(list.get(1), Some(2)); // Second element should be 2
+        // Into an empty buffer
+        let mut buf = Vec::new();
+        list.collect_into(&mut buf);
+        assert_eq!(buf, [1, 2]);
+
+        // Appended after what the buffer already holds
+        list.collect_into(&mut buf);
+        assert_eq!(buf, [1, 2, 1, 2]);
+
+        // An empty list leaves the buffer alone
+        LinkedList::new().collect_into(&mut buf);
+        assert_eq!(buf, [1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_indices_of() {
+        let list: LinkedList<i32> = [1, 2, 1, 3, 1].into_iter().collect();
+        assert_eq!(list.indices_of(&1).collect::<Vec<_>>(), list.val2ix(&1));
+        assert_eq!(list.indices_of(&4).collect::<Vec<_>>(), list.val2ix(&4));
+
+        // Counts how many elements the iterator compares against
+        #[derive(Clone)]
+        struct Counted<'a>(i32, &'a core::cell::Cell<usize>);
+        impl PartialEq for Counted<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.1.set(self.1.get() + 1);
+                self.0 == other.0
+            }
+        }
+
+        let compared = core::cell::Cell::new(0);
+        let list: LinkedList<Counted> = [1, 2, 1, 3, 1]
+            .into_iter()
+            .map(|x| Counted(x, &compared))
+            .collect();
+        let target = Counted(2, &compared);
+        assert_eq!(list.indices_of(&target).next(), Some(1));
+        assert_eq!(compared.get(), 2); // Stopped at the first match
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: LinkedList<i32> = [1, 2, 3].into_iter().collect();
+        let b: LinkedList<i32> = [1, 2, 3].into_iter().collect();
+        let c: LinkedList<i32> = [3, 2, 1].into_iter().collect();
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(hash_of(&a), hash_of(&c));
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        // Doubles the even numbers and drops the odd ones
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=6);
+        list.retain_mut(|x| {
+            if *x % 2 == 0 {
+                *x *= 2;
+                true
+            } else {
+                false
+            }
+        });
+        assert_eq!(format!("{}", list), "(4 -> 8 -> 12)");
+        assert_eq!(list.len(), 3);
+
+        // Dropping everything empties the list
+        list.retain_mut(|_| false);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_insert_by_value() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 2, 3]);
+
+        // Only the first match counts
+        assert_eq!(list.insert_after_first(&2, 5), Ok(()));
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 5 -> 2 -> 3)");
+        assert_eq!(list.insert_before_first(&2, 6), Ok(()));
+        assert_eq!(format!("{}", list), "(1 -> 6 -> 2 -> 5 -> 2 -> 3)");
+
+        // After the tail, before the head
+        assert_eq!(list.insert_after_first(&3, 7), Ok(()));
+        assert_eq!(list.insert_before_first(&1, 0), Ok(()));
+        assert_eq!(
+            format!("{}", list),
+            "(0 -> 1 -> 6 -> 2 -> 5 -> 2 -> 3 -> 7)"
+        );
+        assert_eq!(list.len(), 8);
+
+        // Not found, the list is unchanged
+        assert_eq!(
+            list.insert_after_first(&9, 1),
+            Err(LinkedListError::ValueNotFound)
+        );
+        assert_eq!(
+            list.insert_before_first(&9, 1),
+            Err(LinkedListError::ValueNotFound)
+        );
+        assert_eq!(list.len(), 8);
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(
+            empty.insert_after_first(&1, 1),
+            Err(LinkedListError::ValueNotFound)
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let list: LinkedList<usize> = LinkedList::from_fn(4, |i| i);
+        assert_eq!(format!("{}", list), "(0 -> 1 -> 2 -> 3)");
+        assert_eq!(list.len(), 4);
+
+        // n == 0 never calls f
+        let list: LinkedList<usize> = LinkedList::from_fn(0, |_| unreachable!());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_eq_cmp_by() {
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let b: LinkedList<i64> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert!(a.eq_by(&b, |x, y| *x as i64 == *y));
+        assert_eq!(
+            a.cmp_by(&b, |x, y| (*x as i64).cmp(y)),
+            cmp::Ordering::Equal
+        );
+
+        // Different lengths
+        let short: LinkedList<i64> = LinkedList::from_iter(vec![1, 2]);
+        assert!(!a.eq_by(&short, |x, y| *x as i64 == *y));
+        assert_eq!(
+            a.cmp_by(&short, |x, y| (*x as i64).cmp(y)),
+            cmp::Ordering::Greater
+        );
+        assert_eq!(
+            short.cmp_by(&a, |x, y| x.cmp(&(*y as i64))),
+            cmp::Ordering::Less
+        );
+
+        // A differing element decides before the length does
+        let c: LinkedList<i64> = LinkedList::from_iter(vec![0, 9, 9, 9]);
+        assert!(!a.eq_by(&c, |x, y| *x as i64 == *y));
+        assert_eq!(
+            a.cmp_by(&c, |x, y| (*x as i64).cmp(y)),
+            cmp::Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_run_length() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 1, 1, 2, 3, 3]);
+        let runs = list.run_length_encode();
+        assert_eq!(runs, vec![(1, 3), (2, 1), (3, 2)]);
+        assert_eq!(LinkedList::from_run_length(&runs), list);
+
+        // Round trips
+        for values in [vec![5], vec![1, 2, 1], vec![4, 4, 4, 4]] {
+            let list: LinkedList<i32> = LinkedList::from_iter(values);
+            assert_eq!(LinkedList::from_run_length(&list.run_length_encode()), list);
+        }
+
+        // Empty list and zero counts
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.run_length_encode(), vec![]);
+        assert!(LinkedList::from_run_length(&[(1, 0)]).is_empty());
+    }
+
+    #[test]
+    fn test_iter_cloned() {
+        let mut list: LinkedList<String> =
+            LinkedList::from_iter(vec!["a".to_string(), "b".to_string()]);
+        let owned: Vec<String> = list.iter_cloned().collect();
+        assert_eq!(owned, vec!["a", "b"]);
+
+        // The list is still usable
+        list.push_head("c".to_string());
+        assert_eq!(format!("{}", list), "(c -> a -> b)");
+    }
+
+    #[test]
+    fn test_try_get() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.try_get(0), Ok(1));
+        assert_eq!(list.try_get(2), Ok(3));
+        assert_eq!(list.try_get(3), Err(LinkedListError::IndexOutOfRange));
+
+        // Works with `?`
+        fn sum_ends(list: &LinkedList<i32>) -> Result<i32, LinkedListError> {
+            Ok(list.try_get(0)? + list.try_get(list.len() - 1)?)
+        }
+        assert_eq!(sum_ends(&list), Ok(4));
+        assert_eq!(
+            sum_ends(&LinkedList::from_iter(vec![])),
+            Err(LinkedListError::IndexOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        list.rotate_left(2);
+        assert_eq!(format!("{}", list), "(3 -> 4 -> 5 -> 1 -> 2)");
+        list.rotate_right(2); // Undoes it
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+        list.rotate_right(1);
+        assert_eq!(format!("{}", list), "(5 -> 1 -> 2 -> 3 -> 4)");
+        assert_eq!(list.len(), 5);
+
+        // Whole turns change nothing, larger n wraps around
+        list.rotate_left(5);
+        assert_eq!(format!("{}", list), "(5 -> 1 -> 2 -> 3 -> 4)");
+        list.rotate_left(6);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+        list.push_back(6); // The relinked nodes still end the list properly
+        assert_eq!(list.last(), Some(&6));
+
+        // No-op on empty and single-element lists
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.rotate_left(3);
+        list.rotate_right(3);
+        assert!(list.is_empty());
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1]);
+        list.rotate_right(3);
+        assert_eq!(format!("{}", list), "(1)");
+    }
+
+    #[test]
+    fn test_eq_slice() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert!(list == [1, 2, 3][..]);
+
+        // Length mismatches and differing elements
+        assert_ne!(list, [1, 2]);
+        assert_ne!(list, vec![1, 2, 3, 4]);
+        assert_ne!(list, &[1, 2, 4][..]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty, []);
+        assert_eq!(empty, Vec::new());
+    }
+
+    #[test]
+    fn test_debug() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(format!("{:?}", list), "LinkedList [1, 2, 3]");
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)"); // Display is unchanged
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(format!("{:?}", empty), "LinkedList []");
     }
 
     #[test]
-    fn test_pop_head() {
-        // Test removing elements from the head of the list
-        let mut list = LinkedList::new();
-        assert_eq!(list.pop_head(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+    fn test_get_or_insert_back() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.get_or_insert_back(1), &1); // Into an empty list
+        assert_eq!(list.get_or_insert_back(2), &2);
+        assert_eq!(list.len(), 2);
 
-        list.push_head(1); // Add 1 to the head
-        list.push_head(2); // Add 2 to the head
-        assert_eq!(list.pop_head(), Ok(2)); // Pop should return 2 (head element)
-        assert_eq!(list.len(), 1); // List should now contain 1 element
-        assert_eq!(list.pop_head(), Ok(1)); // Pop should return 1
-        assert_eq!(list.len(), 0); // List should be empty
-        assert_eq!(list.pop_head(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+        // Already present, no growth
+        assert_eq!(list.get_or_insert_back(1), &1);
+        assert_eq!(list.len(), 2);
+        assert_eq!(format!("{}", list), "(1 -> 2)");
+
+        // New, pushed to the back
+        assert_eq!(list.get_or_insert_back(3), &3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
     }
 
     #[test]
-    fn test_pop_back() {
-        // Test removing elements from the back of the list
-        let mut list = LinkedList::new();
-        assert_eq!(list.pop_back(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+    fn test_rfold() {
+        let list: LinkedList<&str> = LinkedList::from_iter(vec!["a", "b", "c"]);
+        assert_eq!(list.rfold(String::new(), |acc, s| acc + s), "cba");
 
-        list.push_back(1); // Add 1 to the back
-        list.push_back(2); // Add 2 to the back
-        list.push_back(3); // Add 3 to the back
-        assert_eq!(list.pop_back(), Ok(3)); // Pop should return 3 (last element)
-        assert_eq!(list.len(), 2); // List should now contain 2 elements
-        assert_eq!(list.pop_back(), Ok(2)); // Pop should return 2
-        assert_eq!(list.len(), 1); // List should now contain 1 element
-        assert_eq!(list.pop_back(), Ok(1)); // Pop should return 1
-        assert_eq!(list.len(), 0); // List should be empty
-        assert_eq!(list.pop_back(), Err(LinkedListError::PopFromEmptyList)); // Pop on an empty list should return an error
+        // Empty list returns the seed
+        let empty: LinkedList<&str> = LinkedList::new();
+        assert_eq!(empty.rfold(String::from("x"), |acc, s| acc + s), "x");
     }
 
     #[test]
-    fn test_insert() {
-        // Test inserting elements at a specific position
-        let mut list = LinkedList::new();
-        assert_eq!(list.insert(1, 1), Err(LinkedListError::InsertOutOfRange)); // Inserting out of range
+    fn test_dedup_by_key() {
+        let mut list: LinkedList<&str> = LinkedList::from_iter(vec!["a", "b", "cd", "ef"]);
+        list.dedup_by_key(|s| s.len());
+        assert_eq!(list, ["a", "cd"]);
+        assert_eq!(list.len(), 2);
 
-        list.push_back(1); // Add 1 to the back
-        list.push_back(3); // Add 3 to the back
-        assert_eq!(list.insert(2, 1), Ok(())); // Insert 2 at position 1
-        assert_eq!(list.len(), 3); // List should contain 3 elements
-        assert_eq!(list.get(1), Some(2)); // Element at position 1 should be 2
+        // All keys distinct, nothing removed
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1, 2]);
+        list.dedup_by_key(|x| *x);
+        assert_eq!(list, [1, 2, 1, 2]);
+        assert_eq!(list.len(), 4);
 
-        assert_eq!(list.insert(4, 3), Ok(())); // Insert 4 at position 3
-        assert_eq!(list.len(), 4); // List should contain 4 elements
-        assert_eq!(list.get(3), Some(4)); // Element at position 3 should be 4
+        // A run that spans the whole list
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6]);
+        list.dedup_by_key(|x| x % 2);
+        assert_eq!(list, [2]);
+    }
 
-        assert_eq!(list.insert(0, 0), Ok(())); // Insert 0 at position 0
-        assert_eq!(list.len(), 5); // List should contain 5 elements
-        assert_eq!(list.get(0), Some(0)); // Element at position 0 should be 0
+    #[test]
+    fn test_nth_ref() {
+        let list: LinkedList<String> =
+            LinkedList::from_iter(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(list.nth_ref(0).map(String::as_str), Some("a"));
+        assert_eq!(list.nth_ref(list.len() - 1).map(String::as_str), Some("b")); // The last one
+        assert_eq!(list.nth_ref(2), None);
+        assert_eq!(list.nth_ref(usize::MAX), None);
 
-        // Attempt to insert out of range
-        assert_eq!(list.insert(5, 6), Err(LinkedListError::InsertOutOfRange)); // Inserting out of range should return an error
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.nth_ref(0), None);
     }
 
     #[test]
-    fn test_remove() {
-        // Test removing elements at a specific position
-        let mut list = LinkedList::new();
-        assert_eq!(list.remove(0), Err(LinkedListError::RemoveFromEmptyList)); // Remove from an empty list should return an error
+    fn test_builder() {
+        let built: LinkedList<i32> = LinkedList::builder()
+            .push_back(2)
+            .push_head(1)
+            .push_back(4)
+            .insert(3, 2)
+            .build();
 
-        list.push_back(1); // Add 1 to the back
-        list.push_back(2); // Add 2 to the back
-        list.push_back(3); // Add 3 to the back
-        assert_eq!(list.remove(1), Ok(2)); // Remove element at position 1 (value 2)
-        assert_eq!(list.len(), 2); // List should now contain 2 elements
-        assert_eq!(list.get(1), Some(3)); // Element at position 1 should be 3
+        let mut list = LinkedList::new();
+        list.push_back(2);
+        list.push_head(1);
+        list.push_back(4);
+        list.insert(3, 2).unwrap();
+        assert_eq!(built, list);
+        assert_eq!(built.len(), 4);
 
-        assert_eq!(list.remove(0), Ok(1)); // Remove element at position 0 (value 1)
-        assert_eq!(list.len(), 1); // List should now contain 1 element
-        assert_eq!(list.get(0), Some(3)); // Element at position 0 should be 3
+        let empty: LinkedList<i32> = LinkedList::builder().build();
+        assert!(empty.is_empty());
+    }
 
-        assert_eq!(list.remove(0), Ok(3)); // Remove last element (value 3)
-        assert_eq!(list.len(), 0); // List should be empty
-        assert_eq!(list.remove(0), Err(LinkedListError::RemoveFromEmptyList)); // Remove from an empty list should return an error
+    #[test]
+    #[should_panic(expected = "insertion index out of range")]
+    fn test_builder_insert_out_of_range() {
+        LinkedList::builder().push_back(1).insert(2, 5);
     }
 
     #[test]
-    fn test_val2ix() {
-        // Test finding indices of a specific value
-        let mut list = LinkedList::new();
-        assert_eq!(list.val2ix(&1), vec![]); // No elements in the list
+    fn test_keep_first_last() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+        list.keep_first(2);
+        assert_eq!(list, [1, 2]);
+        assert_eq!(list.len(), 2);
+        list.push_back(3); // The new last node ends the list properly
+        assert_eq!(list, [1, 2, 3]);
 
-        list.push_back(1); // Add 1 to the back
-        list.push_back(2); // Add 2 to the back
-        list.push_back(3); // Add 3 to the back
-        list.push_back(2); // Add another 2 to the back
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+        list.keep_last(2);
+        assert_eq!(list, [4, 5]);
+        assert_eq!(list.len(), 2);
 
-        assert_eq!(list.val2ix(&1), vec![0]); // 1 is at index 0
-        assert_eq!(list.val2ix(&2), vec![1, 3]); // 2 is at indices 1 and 3
-        assert_eq!(list.val2ix(&3), vec![2]); // 3 is at index 2
-        assert_eq!(list.val2ix(&4), vec![]); // No 4 in the list
+        // No-ops when n >= len, and keeping nothing
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=3);
+        list.keep_first(3);
+        list.keep_last(10);
+        assert_eq!(list, [1, 2, 3]);
+        list.keep_last(0);
+        assert!(list.is_empty());
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=3);
+        list.keep_first(0);
+        assert!(list.is_empty());
     }
 
     #[test]
-    fn test_ix2val() {
-        // Test accessing value by index
-        let mut list = LinkedList::new();
-        list.push_back(10); // Add 10 to the back
-        list.push_back(20); // Add 20 to the back
-        list.push_back(30); // Add 30 to the back
+    fn test_take_skip_while_list() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6, 1, 8]);
+        assert_eq!(list.take_while_list(|&x| x % 2 == 0), [2, 4, 6]);
+        assert_eq!(list.skip_while_list(|&x| x % 2 == 0), [1, 8]); // 8 is kept after the first failure
+        assert_eq!(list.len(), 5); // The list itself is unchanged
 
-        assert_eq!(list.ix2val(0), Some(10)); // Element at index 0 should be 10
-        assert_eq!(list.ix2val(1), Some(20)); // Element at index 1 should be 20
-        assert_eq!(list.ix2val(2), Some(30)); // Element at index 2 should be 30
-        assert_eq!(list.ix2val(3), None); // No element at index 3
+        // The predicate matches everything, or nothing
+        assert_eq!(list.take_while_list(|_| true), list);
+        assert!(list.skip_while_list(|_| true).is_empty());
+        assert!(list.take_while_list(|_| false).is_empty());
+        assert_eq!(list.skip_while_list(|_| false), list);
     }
 
     #[test]
-    fn test_get() {
-        // Test retrieving element at a specific index
-        let mut list = LinkedList::new();
-        list.push_back(100); // Add 100 to the back
-        list.push_back(200); // Add 200 to the back
+    fn test_sum_product() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+        assert_eq!(list.sum(), 10);
+        assert_eq!(list.product(), 24);
 
-        assert_eq!(list.get(0), Some(100)); // Element at index 0 should be 100
-        assert_eq!(list.get(1), Some(200)); // Element at index 1 should be 200
-        assert_eq!(list.get(2), None); // No element at index 2
+        // The empty list gives the identity
+        let empty: LinkedList<f64> = LinkedList::new();
+        assert_eq!(empty.sum(), 0.0);
+        assert_eq!(empty.product(), 1.0);
     }
 
     #[test]
-    fn test_len() {
-        // Test the length of the list
-        let mut list: LinkedList<i32> = LinkedList::new();
-        assert_eq!(list.len(), 0); // Empty list
+    fn test_scan() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+        let sums = list.scan(0, |acc, x| {
+            *acc += x;
+            *acc
+        });
+        assert_eq!(sums, [1, 3, 6, 10]);
 
-        list.push_head(1); // Add 1 to the head
-        assert_eq!(list.len(), 1); // List should contain 1 element
+        // The output does not have to be the new state
+        let previous = list.scan(0, |prev, x| core::mem::replace(prev, *x));
+        assert_eq!(previous, [0, 1, 2, 3]);
 
-        list.push_back(2); // Add 2 to the back
-        assert_eq!(list.len(), 2); // List should contain 2 elements
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.scan(0, |acc, x| *acc + x).is_empty());
+    }
 
-        list.pop_head().unwrap(); // Remove from head
-        assert_eq!(list.len(), 1); // List should contain 1 element
+    #[test]
+    fn test_iter_mut_indexed() {
+        // Doubles the values at even indices
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        for (ix, val) in list.iter_mut_indexed() {
+            if ix % 2 == 0 {
+                *val *= 2;
+            }
+        }
+        assert_eq!(list, [2, 2, 6, 4, 10]);
 
-        list.pop_back().unwrap(); // Remove from back
-        assert_eq!(list.len(), 0); // List should be empty
+        // Indices match the positions seen by indexed_iter
+        let pairs: Vec<(usize, i32)> = list.iter_mut_indexed().map(|(ix, v)| (ix, *v)).collect();
+        let expected: Vec<(usize, i32)> = list.indexed_iter().map(|(ix, v)| (ix, *v)).collect();
+        assert_eq!(pairs, expected);
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn test_display() {
-        // Test the display of the list
-        let mut list = LinkedList::new();
-        assert_eq!(format!("{}", list), "()"); // Empty list
+    fn test_choose() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
 
-        list.push_back(1); // Add 1 to the back
-        assert_eq!(format!("{}", list), "(1)");
+        // A fixed seed always picks the same element (for the StdRng of rand 0.8)
+        let list: LinkedList<i32> = LinkedList::from_iter(1..=10);
+        assert_eq!(list.choose(&mut StdRng::seed_from_u64(42)), Some(&5));
 
-        list.push_back(2); // Add 2 to the back
-        list.push_back(3); // Add 3 to the back
-        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+        // Every element can be picked
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut seen = [false; 10];
+        for _ in 0..200 {
+            seen[*list.choose(&mut rng).unwrap() as usize - 1] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
 
-        list.pop_head().unwrap(); // Remove from head
-        assert_eq!(format!("{}", list), "(2 -> 3)");
+        // A single element is always chosen, an empty list gives None
+        let one: LinkedList<i32> = LinkedList::from_iter(vec![5]);
+        assert_eq!(one.choose(&mut rng), Some(&5));
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.choose(&mut rng), None);
+    }
 
-        list.pop_back().unwrap(); // Remove from back
-        assert_eq!(format!("{}", list), "(2)");
+    #[test]
+    fn test_concat_all() {
+        let lists = vec![
+            LinkedList::from_iter(vec![1, 2]),
+            LinkedList::from_iter(vec![3]),
+            LinkedList::from_iter(vec![4, 5, 6]),
+        ];
+        let mut list = LinkedList::concat_all(lists);
+        assert_eq!(list, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 6);
+        list.push_back(7); // The last node ends the list properly
+        assert_eq!(list.last(), Some(&7));
+
+        // Empty lists in between, at the ends, and no lists at all
+        let lists = vec![
+            LinkedList::new(),
+            LinkedList::from_iter(vec![1]),
+            LinkedList::new(),
+            LinkedList::from_iter(vec![2, 3]),
+            LinkedList::new(),
+        ];
+        let list = LinkedList::concat_all(lists);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert!(LinkedList::<i32>::concat_all(Vec::new()).is_empty());
     }
 
     #[test]
-    fn test_clone() {
-        // Test cloning the list
-        let mut list = LinkedList::new();
-        list.push_back(1); // Add 1 to the back
-        list.push_back(2); // Add 2 to the back
-        list.push_back(3); // Add 3 to the back
+    fn test_interleave() {
+        // Equal lengths
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6]);
+        let list = a.interleave(b);
+        assert_eq!(list, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 6);
 
-        let cloned_list = list.clone(); // Clone the list
-        assert_eq!(cloned_list.len(), 3); // Cloned list should contain 3 elements
-        assert_eq!(cloned_list.get(0), Some(1)); // First element should be 1
-        assert_eq!(cloned_list.get(1), Some(2)); // Second element should be 2
-        assert_eq!(cloned_list.get(2), Some(3)); // Third element should be 3
+        // The longer list's remainder goes at the end, whichever side it is on
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5, 6, 7]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![2, 4]);
+        let mut list = a.interleave(b);
+        assert_eq!(list, [1, 2, 3, 4, 5, 6, 7]);
+        list.push_back(8);
+        assert_eq!(list.len(), 8);
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![2, 3, 4]);
+        assert_eq!(a.interleave(b), [1, 2, 3, 4]);
 
-        // Ensure modifying original list does not affect cloned list
-        list.pop_back().unwrap(); // Modify original list
-        assert_eq!(list.len(), 2); // Original list should have 2 elements
-        assert_eq!(cloned_list.len(), 3); // Cloned list should still have 3 elements
+        // One side empty returns the other unchanged
+        let a: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        assert_eq!(a.interleave(LinkedList::new()), [1, 2]);
+        let b: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        assert_eq!(LinkedList::new().interleave(b), [1, 2]);
     }
 
     #[test]
-    fn test_insert_remove_multiple() {
-        // Test inserting and removing multiple elements
-        let mut list = LinkedList::new();
-        list.push_back(1); // List: 1
-        list.push_back(3); // List: 1 -> 3
-        list.insert(2, 1).unwrap(); // List: 1 -> 2 -> 3
-        list.insert(4, 3).unwrap(); // List: 1 -> 2 -> 3 -> 4
-        list.insert(0, 0).unwrap(); // List: 0 -> 1 -> 2 -> 3 -> 4
-
-        assert_eq!(list.len(), 5);
-        assert_eq!(format!("{}", list), "(0 -> 1 -> 2 -> 3 -> 4)");
+    fn test_partition_point() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.partition_point(|&x| x < 3), 2);
+        assert_eq!(list.partition_point(|_| true), list.len()); // All true
+        assert_eq!(list.partition_point(|_| false), 0); // All false
 
-        // Remove elements from various positions
-        assert_eq!(list.remove(2), Ok(2)); // List: 0 -> 1 -> 3 -> 4
-        assert_eq!(list.remove(0), Ok(0)); // List: 1 -> 3 -> 4
-        assert_eq!(list.remove(2), Ok(4)); // List: 1 -> 3
+        // Same answer as on a slice
+        let values = [1, 2, 3, 4, 5];
+        for pivot in 0..=6 {
+            assert_eq!(
+                list.partition_point(|&x| x < pivot),
+                values.partition_point(|&x| x < pivot)
+            );
+        }
 
-        assert_eq!(list.len(), 2);
-        assert_eq!(format!("{}", list), "(1 -> 3)");
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.partition_point(|_| true), 0);
     }
 
     #[test]
-    fn test_clean() {
-        // Test cleaning the list
-        let mut list = LinkedList::new();
+    fn test_peekable_iter() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let mut iter = list.peekable_iter();
 
-        // Test clean on an empty list
-        list.clean();
-        assert_eq!(list.len(), 0);
-        assert_eq!(format!("{}", list), "()");
+        // Peeking does not advance
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.peek_ahead(1), Some(&2));
+        assert_eq!(iter.peek_ahead(3), None); // Past the end
+        assert_eq!(iter.next(), Some(&1));
 
-        // Test clean on a list with elements
-        list.push_back(1); // Add 1 to the back
-        list.push_back(2); // Add 2 to the back
-        list.push_back(3); // Add 3 to the back
-        assert_eq!(list.len(), 3);
-        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+        assert_eq!(iter.peek(), Some(&2));
+        assert_eq!(iter.peek_ahead(1), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.next(), None);
 
-        // Call clean and ensure the list is empty
-        list.clean();
-        assert_eq!(list.len(), 0);
-        assert_eq!(format!("{}", list), "()");
+        // A simple parser-style use: group equal neighbours
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 1, 2, 3, 3, 3]);
+        let mut iter = list.peekable_iter();
+        let mut counts = Vec::new();
+        while let Some(val) = iter.next() {
+            let mut count = 1;
+            while iter.peek() == Some(val) {
+                iter.next();
+                count += 1;
+            }
+            counts.push(count);
+        }
+        assert_eq!(counts, vec![2, 1, 3]);
     }
 
     #[test]
-    fn test_from_iter() {
-        // Test creating a list from a vector
-        let list: LinkedList<i32> = LinkedList::from_iter(vec![]);
-        assert_eq!(list.len(), 0); // Empty list
-        assert_eq!(format!("{}", list), "()");
+    fn test_is_palindrome() {
+        assert!(LinkedList::from_iter(vec![1, 2, 1]).is_palindrome());
+        assert!(!LinkedList::from_iter(vec![1, 2, 3]).is_palindrome());
+        assert!(LinkedList::from_iter(vec![1, 2, 2, 1]).is_palindrome()); // Even length
+        assert!(!LinkedList::from_iter(vec![1, 2, 1, 1]).is_palindrome());
+        assert!(LinkedList::from_iter("racecar".chars()).is_palindrome());
 
-        let list = LinkedList::from_iter(vec![1, 2, 3]);
-        assert_eq!(list.len(), 3); // List should contain 3 elements
-        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+        // Empty and single-element lists
+        assert!(LinkedList::<i32>::new().is_palindrome());
+        assert!(LinkedList::from_iter(vec![7]).is_palindrome());
+    }
 
-        let list = LinkedList::from_iter(vec![1, 1, 1, 1]);
-        assert_eq!(list.len(), 4); // List should contain 4 elements
-        assert_eq!(format!("{}", list), "(1 -> 1 -> 1 -> 1)");
+    #[test]
+    fn test_dedup_by() {
+        // Case-insensitive strings
+        let mut list: LinkedList<String> = ["foo", "FOO", "Foo", "bar", "Bar", "foo"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(list, ["foo", "bar", "foo"].map(String::from));
+        assert_eq!(list.len(), 3);
 
-        let list = LinkedList::from_iter(vec![1, 1, 1, 1].into_iter());
-        assert_eq!(list.len(), 4); // List should contain 4 elements
-        assert_eq!(format!("{}", list), "(1 -> 1 -> 1 -> 1)");
+        // Each element is compared with the one kept before it, not the one removed
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 10, 11]);
+        list.dedup_by(|a, b| (a - b).abs() < 2);
+        assert_eq!(list, [1, 3, 10]);
+        assert_eq!(list.len(), 3);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.dedup_by(|_, _| true);
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_into_iter() {
-        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5, 6]);
-
-        let it = list.into_iter(); // list is moved
+    fn test_lower_upper_bound() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 2, 2, 3]);
+        assert_eq!(list.lower_bound(&2), 1);
+        assert_eq!(list.upper_bound(&2), 4);
 
-        let vec = it.collect::<Vec<i32>>();
+        // Values that are not in the list
+        assert_eq!(list.lower_bound(&0), 0);
+        assert_eq!(list.upper_bound(&0), 0);
+        assert_eq!(list.lower_bound(&4), 5);
+        assert_eq!(list.upper_bound(&4), 5);
 
-        assert_eq!(vec, vec![1, 2, 3, 4, 5, 6]);
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.lower_bound(&1), 0);
+        assert_eq!(empty.upper_bound(&1), 0);
     }
 
     #[test]
-    fn test_is_empty() {
-        let mut list = LinkedList::new();
-        assert!(list.is_empty());
-        list.push_back(1);
-        assert!(!list.is_empty());
+    fn test_try_fold() {
+        let sum_small = |acc: i32, &x: &i32| {
+            if x > 100 {
+                Err(x)
+            } else {
+                Ok(acc + x)
+            }
+        };
+
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.try_fold(0, sum_small), Ok(6));
+
+        // Stops at the first value over 100, later elements are not visited
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 200, 3, 300]);
+        let mut visited = 0;
+        let res = list.try_fold(0, |acc, x| {
+            visited += 1;
+            sum_small(acc, x)
+        });
+        assert_eq!(res, Err(200));
+        assert_eq!(visited, 2);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.try_fold(7, sum_small), Ok(7));
     }
 
     #[test]
-    fn test_iter() {
-        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
-        let mut iter = list.iter(); // create an borrowed iterator for linked list
+    fn test_all_any() {
+        let evens: LinkedList<i32> = LinkedList::from_iter(vec![2, 4, 6]);
+        let odds: LinkedList<i32> = LinkedList::from_iter(vec![1, 3, 5]);
+        let mixed: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let empty: LinkedList<i32> = LinkedList::new();
+        let is_even = |&x: &i32| x % 2 == 0;
 
-        assert_eq!(iter.next(), Some(&1));
-        assert_eq!(iter.next(), Some(&2));
-        assert_eq!(iter.next(), Some(&3));
-        assert_eq!(iter.next(), Some(&4));
-        assert_eq!(iter.next(), Some(&5));
-        assert_eq!(iter.next(), None);
+        assert!(evens.all(is_even));
+        assert!(!odds.all(is_even));
+        assert!(!mixed.all(is_even));
+        assert!(empty.all(is_even));
+
+        assert!(evens.any(is_even));
+        assert!(!odds.any(is_even));
+        assert!(mixed.any(is_even));
+        assert!(!empty.any(is_even));
+
+        // Both stop at the first element that decides the result
+        let mut visited = 0;
+        assert!(!mixed.all(|&x| {
+            visited += 1;
+            x < 1
+        }));
+        assert_eq!(visited, 1);
+
+        let mut visited = 0;
+        assert!(mixed.any(|&x| {
+            visited += 1;
+            x == 2
+        }));
+        assert_eq!(visited, 2);
     }
 
     #[test]
-    fn test_iter_mut() {
-        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
-        let mut iter = list.iter_mut(); // create a mutable borrowed iterator for linked list
-
-        assert_eq!(iter.next(), Some(&mut 1));
-        assert_eq!(iter.next(), Some(&mut 2));
-        assert_eq!(iter.next(), Some(&mut 3));
-        assert_eq!(iter.next(), Some(&mut 4));
-        assert_eq!(iter.next(), Some(&mut 5));
-        assert_eq!(iter.next(), None);
+    fn test_max_min_by_key() {
+        let list: LinkedList<&str> = LinkedList::from_iter(vec!["a", "ccc", "bb"]);
+        assert_eq!(list.max_by_key(|s| s.len()), Some(&"ccc"));
+        assert_eq!(list.min_by_key(|s| s.len()), Some(&"a"));
 
-        for val in list.iter_mut() {
-            *val *= *val;
-        }
+        // Ties go to the first element
+        let list: LinkedList<&str> = LinkedList::from_iter(vec!["xx", "a", "yy", "b"]);
+        assert_eq!(list.max_by_key(|s| s.len()), Some(&"xx"));
+        assert_eq!(list.min_by_key(|s| s.len()), Some(&"a"));
 
-        assert_eq!(format!("{}", list), "(1 -> 4 -> 9 -> 16 -> 25)");
+        let empty: LinkedList<&str> = LinkedList::new();
+        assert_eq!(empty.max_by_key(|s| s.len()), None);
+        assert_eq!(empty.min_by_key(|s| s.len()), None);
     }
 }
@@ -0,0 +1,379 @@
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// `LinkedListNode` represents a single node in a circular linked list. The `next` of the last
+/// node is the first node, so it is never empty: a single node points to itself.
+struct LinkedListNode<T> {
+    value: T,                         // The value stored in the node.
+    next: NonNull<LinkedListNode<T>>, // A reference to the next node in the ring.
+}
+
+/// A singly linked list whose last node links back to the first, with a built-in cursor.
+///
+/// # Attributes
+///
+/// * `len` - The length of the list.
+/// * `prev` - The node before the cursor, or `None` if the list is empty.
+///
+/// # Explanation
+///
+/// The list is a ring with no first or last element, only a current one, which `advance` moves
+/// forward forever, wrapping around past the element pushed last. This suits round-robin use such
+/// as scheduling: look at `current`, `advance` to the next one, and `remove_current` once an entry
+/// is done.
+///
+/// The list keeps the node *before* the cursor rather than the current node itself, so that both
+/// `push` (which inserts right before the current element) and `remove_current` can relink the ring
+/// without walking all the way around it.
+///
+/// # Examples
+///
+/// ```rust
+/// use hym::circular_linked_list::CircularLinkedList;
+///
+/// let mut tasks = CircularLinkedList::new();
+/// tasks.push("a");
+/// tasks.push("b");
+/// assert_eq!(tasks.current(), Some(&"a"));
+/// assert_eq!(tasks.advance(), &"b");
+/// assert_eq!(tasks.advance(), &"a"); // wraps around
+/// ```
+pub struct CircularLinkedList<T> {
+    len: usize,
+    prev: Option<NonNull<LinkedListNode<T>>>,
+    _marker: PhantomData<T>, // Used to handle covariance and drop check.
+}
+
+impl<T> CircularLinkedList<T> {
+    /// Creates a new empty circular list.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - An empty circular list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let list = CircularLinkedList::<u32>::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            prev: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds a value at the end of the rotation, right before the current element.
+    ///
+    /// The cursor does not move. Into an empty list, the new value becomes the current element.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to be added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// list.push(3);
+    /// assert_eq!(list.current(), Some(&1));
+    /// assert_eq!(list.advance(), &2);
+    /// assert_eq!(list.advance(), &3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn push(&mut self, val: T) {
+        let node_ptr = NonNull::from(Box::leak(Box::new(LinkedListNode {
+            value: val,
+            next: NonNull::dangling(), // Linked below, before anything can read it.
+        })));
+
+        unsafe {
+            match self.prev {
+                Some(prev) => {
+                    (*node_ptr.as_ptr()).next = (*prev.as_ptr()).next;
+                    (*prev.as_ptr()).next = node_ptr;
+                }
+                None => (*node_ptr.as_ptr()).next = node_ptr, // A ring of one node.
+            }
+        }
+
+        self.prev = Some(node_ptr);
+        self.len += 1;
+    }
+
+    /// Returns a reference to the current element.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the element under the cursor.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let mut list = CircularLinkedList::new();
+    /// assert_eq!(list.current(), None);
+    /// list.push(1);
+    /// assert_eq!(list.current(), Some(&1));
+    /// ```
+    pub fn current(&self) -> Option<&T> {
+        self.prev
+            .map(|prev| unsafe { &(*(*prev.as_ptr()).next.as_ptr()).value })
+    }
+
+    /// Moves the cursor to the next element and returns it, wrapping around after the last one.
+    ///
+    /// # Returns
+    ///
+    /// * `&T` - A reference to the new current element.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(1);
+    /// assert_eq!(list.advance(), &1); // a single element follows itself
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn advance(&mut self) -> &T {
+        let prev = self.prev.expect("advance on an empty CircularLinkedList");
+        unsafe {
+            let current = (*prev.as_ptr()).next;
+            self.prev = Some(current);
+            &(*(*current.as_ptr()).next.as_ptr()).value
+        }
+    }
+
+    /// Removes the current element and returns it. The cursor moves onto the element that followed it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The removed value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// assert_eq!(list.remove_current(), Some(1));
+    /// assert_eq!(list.current(), Some(&2));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn remove_current(&mut self) -> Option<T> {
+        let prev = self.prev?;
+        unsafe {
+            let current = (*prev.as_ptr()).next;
+            if current == prev {
+                // The only node points to itself, the ring is now empty.
+                self.prev = None;
+            } else {
+                (*prev.as_ptr()).next = (*current.as_ptr()).next;
+            }
+            self.len -= 1;
+            Some(Box::from_raw(current.as_ptr()).value)
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of elements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let mut list = CircularLinkedList::new();
+    /// list.push(1);
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the list is empty.
+    /// * `false` - Otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::circular_linked_list::CircularLinkedList;
+    ///
+    /// let list = CircularLinkedList::<i32>::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Default for CircularLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The ring is owned through `prev`, its tail (the node before the cursor): it is the only
+// pointer into the ring from outside, and the nodes reach each other only through their own `next`
+// links. Nothing else can alias them, so moving the list to another thread moves the `T`s with it,
+// and a shared `&CircularLinkedList` only hands out `&T`.
+unsafe impl<T: Send> Send for CircularLinkedList<T> {}
+
+unsafe impl<T: Sync> Sync for CircularLinkedList<T> {}
+
+impl<T> Drop for CircularLinkedList<T> {
+    /// Frees the nodes one at a time.
+    ///
+    /// Following `next` until it runs out would never stop on a ring, so each node is unlinked
+    /// (its predecessor pointed past it) before it is freed, until the last node, which points to
+    /// itself, is freed on its own.
+    fn drop(&mut self) {
+        while self.remove_current().is_some() {}
+    }
+}
+
+// Unit Test for CircularLinkedList
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_push() {
+        let mut list = CircularLinkedList::new();
+        list.push(1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.current(), Some(&1));
+
+        // Pushes go to the end of the rotation, the cursor stays on 1
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.current(), Some(&1));
+    }
+
+    #[test]
+    fn test_advance_wraps() {
+        let mut list = CircularLinkedList::new();
+        for i in 1..=3 {
+            list.push(i);
+        }
+
+        // Goes around the ring several times
+        let seen: Vec<i32> = (0..7).map(|_| *list.advance()).collect();
+        assert_eq!(seen, [2, 3, 1, 2, 3, 1, 2]);
+
+        // A push lands right before the current element, so it is visited last
+        list.push(4);
+        let seen: Vec<i32> = (0..4).map(|_| *list.advance()).collect();
+        assert_eq!(seen, [3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_single_element() {
+        // A single node points to itself
+        let mut list = CircularLinkedList::new();
+        list.push(1);
+        assert_eq!(list.advance(), &1);
+        assert_eq!(list.advance(), &1);
+        assert_eq!(list.remove_current(), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.current(), None);
+        assert_eq!(list.remove_current(), None);
+
+        // The list is usable again after being emptied
+        list.push(2);
+        assert_eq!(list.advance(), &2);
+    }
+
+    #[test]
+    #[should_panic(expected = "advance on an empty CircularLinkedList")]
+    fn test_advance_empty() {
+        let mut list = CircularLinkedList::<i32>::new();
+        list.advance();
+    }
+
+    #[test]
+    fn test_remove_current() {
+        let mut list = CircularLinkedList::new();
+        for i in 1..=4 {
+            list.push(i);
+        }
+        list.advance(); // On 2
+        assert_eq!(list.remove_current(), Some(2));
+        assert_eq!(list.current(), Some(&3));
+        assert_eq!(list.len(), 3);
+
+        // Removing the element pushed last closes the ring back to the first one
+        list.advance(); // On 4
+        assert_eq!(list.remove_current(), Some(4));
+        assert_eq!(list.current(), Some(&1));
+        let seen: Vec<i32> = (0..4).map(|_| *list.advance()).collect();
+        assert_eq!(seen, [3, 1, 3, 1]);
+    }
+
+    #[test]
+    fn test_drop() {
+        // Every value is dropped exactly once, the ring does not keep any alive
+        let marker = Rc::new(());
+        let mut list = CircularLinkedList::new();
+        for _ in 0..5 {
+            list.push(Rc::clone(&marker));
+        }
+        list.advance();
+        list.remove_current();
+        assert_eq!(Rc::strong_count(&marker), 5);
+        drop(list);
+        assert_eq!(Rc::strong_count(&marker), 1);
+
+        // A single self-linked node, and an empty list
+        let mut list = CircularLinkedList::new();
+        list.push(Rc::clone(&marker));
+        drop(list);
+        assert_eq!(Rc::strong_count(&marker), 1);
+        drop(CircularLinkedList::<Rc<()>>::default());
+    }
+}
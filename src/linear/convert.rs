@@ -0,0 +1,132 @@
+//! Conversions between the three list backends.
+//!
+//! Each conversion clones the elements of the source list into a new list of the target backend,
+//! keeping their order.
+
+use core::cmp;
+
+use crate::box_linked_list::LinkedList as BoxLinkedList;
+use crate::nonull_linked_list::LinkedList as NonNullLinkedList;
+use crate::rc_linked_list::LinkedList as RcLinkedList;
+
+impl<T: Clone + cmp::PartialEq> From<&BoxLinkedList<T>> for NonNullLinkedList<T> {
+    /// Builds a NonNull list holding clones of the box list's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::box_linked_list::LinkedList as BoxLinkedList;
+    /// use hym::nonull_linked_list::LinkedList as NonNullLinkedList;
+    ///
+    /// let src: BoxLinkedList<i32> = BoxLinkedList::from_iter(vec![1, 2, 3]);
+    /// let list = NonNullLinkedList::from(&src);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    fn from(src: &BoxLinkedList<T>) -> Self {
+        src.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone + cmp::PartialEq> From<&BoxLinkedList<T>> for RcLinkedList<T> {
+    /// Builds an Rc list holding clones of the box list's elements.
+    fn from(src: &BoxLinkedList<T>) -> Self {
+        src.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone + cmp::PartialEq> From<&NonNullLinkedList<T>> for BoxLinkedList<T> {
+    /// Builds a box list holding clones of the NonNull list's elements.
+    fn from(src: &NonNullLinkedList<T>) -> Self {
+        src.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone + cmp::PartialEq> From<&NonNullLinkedList<T>> for RcLinkedList<T> {
+    /// Builds an Rc list holding clones of the NonNull list's elements.
+    fn from(src: &NonNullLinkedList<T>) -> Self {
+        src.iter().cloned().collect()
+    }
+}
+
+impl<T: Clone + cmp::PartialEq> From<&RcLinkedList<T>> for BoxLinkedList<T> {
+    /// Builds a box list holding clones of the Rc list's elements.
+    fn from(src: &RcLinkedList<T>) -> Self {
+        src.iter().collect()
+    }
+}
+
+impl<T: Clone + cmp::PartialEq> From<&RcLinkedList<T>> for NonNullLinkedList<T> {
+    /// Builds a NonNull list holding clones of the Rc list's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList as NonNullLinkedList;
+    /// use hym::rc_linked_list::LinkedList as RcLinkedList;
+    ///
+    /// let src: RcLinkedList<i32> = RcLinkedList::from_iter(vec![1, 2, 3]);
+    /// let list = NonNullLinkedList::from(&src);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    fn from(src: &RcLinkedList<T>) -> Self {
+        src.iter().collect()
+    }
+}
+
+// Unit Test for the conversions
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_round_trips() {
+        let src: BoxLinkedList<i32> = BoxLinkedList::from_iter(vec![1, 2, 3]);
+
+        let nonull = NonNullLinkedList::from(&src);
+        assert_eq!(nonull.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(BoxLinkedList::from(&nonull), src);
+
+        let rc = RcLinkedList::from(&src);
+        assert_eq!(rc.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(BoxLinkedList::from(&rc), src);
+    }
+
+    #[test]
+    fn test_nonull_rc_round_trips() {
+        let src: NonNullLinkedList<String> =
+            NonNullLinkedList::from_iter(["a", "b", "c"].map(String::from));
+
+        let rc = RcLinkedList::from(&src);
+        assert_eq!(rc.len(), 3);
+        assert_eq!(format!("{}", rc), "(a -> b -> c)");
+
+        let back = NonNullLinkedList::from(&rc);
+        assert_eq!(back.len(), 3);
+        assert_eq!(format!("{}", back), "(a -> b -> c)");
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty: BoxLinkedList<i32> = BoxLinkedList::new();
+        assert!(NonNullLinkedList::from(&empty).is_empty());
+        assert!(RcLinkedList::from(&empty).is_empty());
+        assert!(BoxLinkedList::from(&RcLinkedList::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_hash_agrees() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Both lists hash the length, then the elements in order
+        let boxed: BoxLinkedList<i32> = BoxLinkedList::from_iter(vec![1, 2, 3]);
+        let nonull = NonNullLinkedList::from(&boxed);
+        assert_eq!(hash_of(&boxed), hash_of(&nonull));
+    }
+}
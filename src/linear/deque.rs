@@ -0,0 +1,136 @@
+use crate::nonull_linked_list::LinkedList;
+
+/// A double-ended queue backed by the NonNull linked list.
+///
+/// # Explanation
+///
+/// `Deque` exposes the list under the names used by `std::collections::VecDeque`. Unlike a
+/// `VecDeque`, elements are never moved once pushed, so their addresses stay stable for as long as
+/// they are in the deque.
+///
+/// `pop_back` is O(n): the underlying list is singly linked and has to walk to the second-to-last node.
+///
+/// # Examples
+///
+/// ```rust
+/// use hym::deque::Deque;
+///
+/// let mut deque = Deque::new();
+/// deque.push_back(2);
+/// deque.push_front(1);
+/// deque.push_back(3);
+/// assert_eq!(deque.front(), Some(&1));
+/// assert_eq!(deque.back(), Some(&3));
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Deque<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Deque<T> {
+    /// Creates a new, empty deque.
+    pub fn new() -> Self {
+        Deque {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Adds an element to the front of the deque.
+    pub fn push_front(&mut self, val: T) {
+        self.list.push_head(val);
+    }
+
+    /// Adds an element to the back of the deque.
+    pub fn push_back(&mut self, val: T) {
+        self.list.push_back(val);
+    }
+
+    /// Removes and returns the front element, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_head().ok()
+    }
+
+    /// Removes and returns the back element, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back().ok()
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.list.first()
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.list.last()
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the deque holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Unit Test for Deque
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_front_and_back() {
+        let mut deque = Deque::new();
+        assert!(deque.is_empty());
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back(), None);
+
+        deque.push_back(2); // [2]
+        deque.push_front(1); // [1, 2]
+        deque.push_back(3); // [1, 2, 3]
+        deque.push_front(0); // [0, 1, 2, 3]
+        assert_eq!(deque.len(), 4);
+        assert_eq!(deque.front(), Some(&0));
+        assert_eq!(deque.back(), Some(&3));
+
+        assert_eq!(deque.pop_back(), Some(3)); // [0, 1, 2]
+        assert_eq!(deque.pop_front(), Some(0)); // [1, 2]
+        assert_eq!(deque.back(), Some(&2));
+        assert_eq!(deque.pop_back(), Some(2)); // [1]
+        assert_eq!(deque.front(), deque.back()); // Single element is both ends
+        assert_eq!(deque.pop_front(), Some(1)); // []
+
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_queue_order() {
+        // push_back + pop_front keeps FIFO order, push_front + pop_front is LIFO
+        let mut deque = Deque::default();
+        for i in 0..5 {
+            deque.push_back(i);
+        }
+        let fifo: Vec<_> = std::iter::from_fn(|| deque.pop_front()).collect();
+        assert_eq!(fifo, vec![0, 1, 2, 3, 4]);
+
+        for i in 0..5 {
+            deque.push_front(i);
+        }
+        let lifo: Vec<_> = std::iter::from_fn(|| deque.pop_front()).collect();
+        assert_eq!(lifo, vec![4, 3, 2, 1, 0]);
+    }
+}
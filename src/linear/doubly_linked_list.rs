@@ -0,0 +1,676 @@
+use alloc::boxed::Box;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// `LinkedListNode` represents a single node in a doubly linked list, containing a value and
+/// references to the previous and next nodes.
+struct LinkedListNode<T> {
+    value: T,                                 // The value stored in the node.
+    prev: Option<NonNull<LinkedListNode<T>>>, // A reference to the previous node in the list, if any.
+    next: Option<NonNull<LinkedListNode<T>>>, // A reference to the next node in the list, if any.
+}
+
+/// A doubly linked list built on `NonNull` pointers in both directions.
+///
+/// # Attributes
+///
+/// * `len` - The length of the list.
+/// * `head` - A reference to the first node in the list.
+/// * `tail` - A reference to the last node in the list.
+///
+/// # Explanation
+///
+/// Every node links to both of its neighbours, so the list can be changed and walked from either
+/// end in O(1) per step. Unlike the Rc list, the back links are plain pointers rather than `Weak`
+/// references, so there are no reference counts to keep up and nothing to upgrade. The list owns
+/// all its nodes and frees them in `Drop`.
+///
+/// The list only needs `alloc`, so it is available without the `std` feature.
+pub struct LinkedList<T> {
+    len: usize,
+    head: Option<NonNull<LinkedListNode<T>>>,
+    tail: Option<NonNull<LinkedListNode<T>>>,
+    _marker: PhantomData<T>, // Used to handle covariance and drop check.
+}
+
+impl<T> LinkedList<T> {
+    /// Creates a new empty linked list.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - An empty linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::<u32>::new();
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            head: None,
+            tail: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds a new node with the given value to the front (head) of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to be added to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_front(1);
+    /// list.push_front(2);
+    /// assert_eq!(format!("{}", list), "(2 -> 1)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn push_front(&mut self, val: T) {
+        let node_ptr = NonNull::from(Box::leak(Box::new(LinkedListNode {
+            value: val,
+            prev: None,
+            next: self.head,
+        })));
+
+        match self.head {
+            Some(old_head) => unsafe { (*old_head.as_ptr()).prev = Some(node_ptr) },
+            None => self.tail = Some(node_ptr),
+        }
+
+        self.head = Some(node_ptr);
+        self.len += 1;
+    }
+
+    /// Adds a new node with the given value to the end (tail) of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to be added to the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(format!("{}", list), "(1 -> 2)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn push_back(&mut self, val: T) {
+        let node_ptr = NonNull::from(Box::leak(Box::new(LinkedListNode {
+            value: val,
+            prev: self.tail,
+            next: None,
+        })));
+
+        match self.tail {
+            Some(old_tail) => unsafe { (*old_tail.as_ptr()).next = Some(node_ptr) },
+            None => self.head = Some(node_ptr),
+        }
+
+        self.tail = Some(node_ptr);
+        self.len += 1;
+    }
+
+    /// Removes and returns the value at the front (head) of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The value from the removed head node.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from_iter(vec![1, 2]);
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_front(), Some(2));
+    /// assert_eq!(list.pop_front(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|node_ptr| unsafe {
+            let node = Box::from_raw(node_ptr.as_ptr());
+            self.head = node.next;
+            match self.head {
+                Some(new_head) => (*new_head.as_ptr()).prev = None,
+                None => self.tail = None,
+            }
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Removes and returns the value at the end (tail) of the list.
+    ///
+    /// The tail node knows its predecessor, so unlike the singly linked lists this does not walk
+    /// the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The value from the removed tail node.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from_iter(vec![1, 2]);
+    /// assert_eq!(list.pop_back(), Some(2));
+    /// assert_eq!(list.pop_back(), Some(1));
+    /// assert_eq!(list.pop_back(), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|node_ptr| unsafe {
+            let node = Box::from_raw(node_ptr.as_ptr());
+            self.tail = node.prev;
+            match self.tail {
+                Some(new_tail) => (*new_tail.as_ptr()).next = None,
+                None => self.head = None,
+            }
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Returns a reference to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the head value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns a reference to the last element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the tail value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of elements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from_iter(vec![1, 2]);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the list is empty.
+    /// * `false` - Otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::<i32>::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over references to the elements, usable from both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let list = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter(&self) -> LinkedListBorrowIterator<'_, T> {
+        LinkedListBorrowIterator {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements, usable from both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::doubly_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::from_iter(vec![1, 2, 3]);
+    /// for val in list.iter_mut().rev().take(2) {
+    ///     *val *= 10;
+    /// }
+    /// assert_eq!(format!("{}", list), "(1 -> 20 -> 30)");
+    /// ```
+    pub fn iter_mut(&mut self) -> LinkedListBorrowMutIterator<'_, T> {
+        LinkedListBorrowMutIterator {
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    /// Creates a `LinkedList` from an iterator, keeping the order of its values.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        for item in iter {
+            list.push_back(item);
+        }
+        list
+    }
+}
+
+// The list owns every node it points to, so sending or sharing a `LinkedList<T>` is the same as
+// sending or sharing the `T`s it holds, as for the NonNull list.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T> Drop for LinkedList<T> {
+    /// Frees the nodes one at a time, from the head.
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    /// Formats the list as a string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (ix, val) in self.iter().enumerate() {
+            if ix > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", val)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Iterator for `LinkedList<T>`.
+///
+/// Owns the remaining values and pops them from either end of the list it was made from.
+pub struct LinkedListIterator<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for LinkedListIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for LinkedListIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = LinkedListIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedListIterator { list: self }
+    }
+}
+
+/// Borrowed iterator for `LinkedList<T>`.
+///
+/// `front` and `back` are the next nodes to yield from each end; `len` counts the nodes between
+/// them, so the two ends stop when they meet.
+pub struct LinkedListBorrowIterator<'a, T> {
+    front: Option<NonNull<LinkedListNode<T>>>,
+    back: Option<NonNull<LinkedListNode<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a T>, // Ensures the iterator is tied to the list's lifetime.
+}
+
+// Not derived: the derive would require `T: Clone`.
+impl<'a, T> Clone for LinkedListBorrowIterator<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front,
+            back: self.back,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LinkedListBorrowIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.front.map(|node| unsafe {
+            let node_ref = &*node.as_ptr();
+            self.front = node_ref.next;
+            &node_ref.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for LinkedListBorrowIterator<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.back.map(|node| unsafe {
+            let node_ref = &*node.as_ptr();
+            self.back = node_ref.prev;
+            &node_ref.value
+        })
+    }
+}
+
+/// Mutable borrowed iterator for `LinkedList<T>`.
+///
+/// Works like `LinkedListBorrowIterator`; `len` also guarantees that the two ends never hand out
+/// the same node twice.
+pub struct LinkedListBorrowMutIterator<'a, T> {
+    front: Option<NonNull<LinkedListNode<T>>>,
+    back: Option<NonNull<LinkedListNode<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>, // Ensures the iterator is tied to the list's lifetime.
+}
+
+impl<'a, T> Iterator for LinkedListBorrowMutIterator<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.front.map(|node| unsafe {
+            let node_ref = &mut *node.as_ptr();
+            self.front = node_ref.next;
+            &mut node_ref.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for LinkedListBorrowMutIterator<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.back.map(|node| unsafe {
+            let node_ref = &mut *node.as_ptr();
+            self.back = node_ref.prev;
+            &mut node_ref.value
+        })
+    }
+}
+
+// Unit Test for LinkedList
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::string::{String, ToString};
+    use std::vec;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_push_front() {
+        // Test adding elements to the front of the list
+        let mut list = LinkedList::new();
+        list.push_front(1); // Add 1 to the front
+        assert_eq!(list.len(), 1); // List should contain 1 element
+        assert_eq!(list.front(), Some(&1)); // Head and tail are the same node
+        assert_eq!(list.back(), Some(&1));
+
+        list.push_front(2); // Add 2 to the front
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&1));
+    }
+
+    #[test]
+    fn test_push_back() {
+        // Test adding elements to the back of the list
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{}", list), "(0 -> 1 -> 2)");
+    }
+
+    #[test]
+    fn test_pop_front() {
+        // Test removing elements from the front of the list
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_front(), None); // Pop on an empty list
+
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.back(), Some(&2));
+        assert_eq!(list.pop_front(), Some(2));
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None); // The tail is cleared as well
+
+        list.push_back(3); // The list is usable again
+        assert_eq!(format!("{}", list), "(3)");
+    }
+
+    #[test]
+    fn test_pop_back() {
+        // Test removing elements from the back of the list
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_back(), None); // Pop on an empty list
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.back(), Some(&2)); // The new tail comes from the prev link
+        list.push_back(4); // and links forward correctly
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 4)");
+
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None); // The head is cleared as well
+    }
+
+    #[test]
+    fn test_pop_back_long() {
+        // Emptying a long list from the back, each step unlinks the tail through its prev link
+        let mut list: LinkedList<usize> = (0..1000).collect();
+        for expected in (0..1000).rev() {
+            assert_eq!(list.pop_back(), Some(expected));
+            assert_eq!(list.len(), expected);
+        }
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let list: LinkedList<i32> = (1..=5).collect();
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+        assert_eq!(
+            list.iter().rev().collect::<Vec<_>>(),
+            vec![&5, &4, &3, &2, &1]
+        );
+        assert_eq!(list.iter().size_hint(), (5, Some(5)));
+
+        // Both ends stop where they meet
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.iter().next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+        for val in list.iter_mut() {
+            *val *= 10;
+        }
+        assert_eq!(format!("{}", list), "(10 -> 20 -> 30 -> 40)");
+
+        // Alternating ends visits every node exactly once
+        let mut iter = list.iter_mut();
+        while let Some(val) = iter.next_back() {
+            *val += 1;
+            if let Some(val) = iter.next() {
+                *val += 2;
+            }
+        }
+        assert_eq!(format!("{}", list), "(12 -> 22 -> 31 -> 41)");
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list: LinkedList<i32> = (1..=4).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_drop() {
+        // Every value is dropped exactly once: by pops, by the iterator, or by the list itself
+        let marker = Rc::new(());
+        let mut list = LinkedList::new();
+        for _ in 0..10 {
+            list.push_back(Rc::clone(&marker));
+        }
+        list.pop_front();
+        list.pop_back();
+        let mut iter = list.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(Rc::strong_count(&marker), 7);
+        drop(iter); // Drops the remaining six
+        assert_eq!(Rc::strong_count(&marker), 1);
+
+        let list: LinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        drop(list);
+    }
+
+    #[test]
+    fn test_default() {
+        let list: LinkedList<i32> = LinkedList::default();
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", list), "()");
+    }
+}
@@ -1,15 +1,32 @@
 pub mod box_linked_list;
+#[cfg(feature = "circular_linked_list")]
+pub mod circular_linked_list;
+#[cfg(feature = "std")]
+mod convert;
+#[cfg(all(feature = "std", feature = "nonull_linked_list"))]
+pub mod deque;
+#[cfg(feature = "doubly_linked_list")]
+pub mod doubly_linked_list;
+#[cfg(feature = "std")]
 pub mod nonull_linked_list;
+#[cfg(feature = "std")]
 pub mod rc_linked_list;
+#[cfg(feature = "sync_linked_list")]
+pub mod sync_linked_list;
 
 #[cfg(feature = "box_linked_list")]
 pub use box_linked_list::*;
 
-#[cfg(feature = "rc_linked_list")]
+#[cfg(all(feature = "std", feature = "rc_linked_list"))]
 pub use rc_linked_list::*;
 
-// TODO
-#[cfg(feature = "nonull_linked_list")]
+#[cfg(all(feature = "std", feature = "nonull_linked_list"))]
 pub use nonull_linked_list::*;
 
+#[cfg(all(feature = "std", feature = "nonull_linked_list"))]
+pub use deque::Deque;
+
+#[cfg(feature = "doubly_linked_list")]
+pub use doubly_linked_list::LinkedList as DoublyLinkedList;
+
 pub mod stack;
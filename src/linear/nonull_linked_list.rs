@@ -1,5 +1,7 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::mem::MaybeUninit;
 use std::ptr::NonNull;
 
 /// `LinkedListNode` represents a single node in a linked list containing a value and a reference to the next node.
@@ -23,7 +25,7 @@ impl<T> LinkedListNode<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedListNode;
+    /// use hym::nonull_linked_list::LinkedListNode;
     ///
     /// let node = LinkedListNode::new(1);
     /// ```
@@ -42,6 +44,27 @@ impl<T> LinkedListNode<T> {
     unsafe fn from_raw(ptr: NonNull<Self>) -> &'static mut Self {
         &mut *ptr.as_ptr()
     }
+
+    /// Returns a reference to the value stored in the node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedListNode;
+    ///
+    /// let node = LinkedListNode::new(1);
+    /// assert_eq!(node.value(), &1);
+    /// ```
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the pointer to the next node, or `None` for the last node of a list.
+    ///
+    /// See `LinkedList::head_ptr` for when the pointer may be dereferenced.
+    pub fn next_ptr(&self) -> Option<NonNull<LinkedListNode<T>>> {
+        self.next
+    }
 }
 
 /// Error type for LinkedList.
@@ -69,17 +92,23 @@ pub enum LinkedListError {
 /// * `len` - The length of the list.
 /// * `head` - A reference to the first node in the list.
 /// * `tail` - A reference to the last node in the list, used to optimize tail operations.
+/// * `pool` - Free nodes kept for reuse, if the list was created with `with_pool`.
 ///
 /// # Explanation
 ///
 /// The `LinkedList` struct represents a linked list data structure. It contains the length of the list,
 /// a reference to the first node in the list, and a reference to the last node in the list.
+///
+/// A list created with `with_pool` does not free the nodes it removes. Their values are moved out and
+/// the empty nodes are kept in `pool`, where the next pushes pick them up again instead of asking the
+/// allocator. Pooled nodes are only returned to the allocator when the list is dropped.
 #[derive(Debug)]
 pub struct LinkedList<T> {
     len: usize,
     head: Option<NonNull<LinkedListNode<T>>>,
     tail: Option<NonNull<LinkedListNode<T>>>,
-    _marker: PhantomData<T>, // Used to handle covariance and drop check.
+    pool: Option<Vec<NonNull<LinkedListNode<T>>>>, // Nodes whose value slot is uninitialized.
+    _marker: PhantomData<T>,                       // Used to handle covariance and drop check.
 }
 
 impl<T> LinkedList<T> {
@@ -92,7 +121,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let list = LinkedList::<u32>::new();
     /// assert_eq!(list.len(), 0);
@@ -102,10 +131,157 @@ impl<T> LinkedList<T> {
             len: 0,
             head: None,
             tail: None,
+            pool: None,
             _marker: PhantomData,
         }
     }
 
+    /// Creates a new empty linked list that recycles its nodes.
+    ///
+    /// Nodes removed from the list (by `pop_head`, `pop_back`, `remove` or `clean`) are kept in an
+    /// internal free list and reused by later pushes and inserts, so a list that is repeatedly filled
+    /// and cleaned stops hitting the allocator after the first round.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - An empty, pooled linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::with_pool();
+    /// for round in 0..3 {
+    ///     for i in 0..100 {
+    ///         list.push_back(round * 100 + i); // only the first round allocates
+    ///     }
+    ///     list.clean();
+    /// }
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn with_pool() -> Self {
+        Self {
+            pool: Some(Vec::new()),
+            ..Self::new()
+        }
+    }
+
+    /// Creates a new empty pooled linked list with `capacity` nodes already allocated.
+    ///
+    /// The first `capacity` pushes or inserts take their nodes from the pool and do not allocate.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of nodes to allocate up front.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - An empty, pooled linked list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::with_capacity(3);
+    /// list.push_back(1); // no allocation
+    /// list.push_back(2); // no allocation
+    /// list.push_head(0); // no allocation
+    /// assert_eq!(format!("{}", list), "(0 -> 1 -> 2)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut list = Self::with_pool();
+        list.reserve(capacity);
+        list
+    }
+
+    /// Allocates `additional` more nodes into the pool.
+    ///
+    /// A list created with `new` switches to pooled mode for good, as if it had been created with
+    /// `with_pool`: from then on, nodes removed by `pop_head`, `pop_back`, `remove` or `clean` are
+    /// kept in the pool instead of being freed. The list then holds on to as many nodes as it has
+    /// ever had at once (or reserved), until `shrink_to_fit` frees the pooled ones or the list is
+    /// dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - The number of nodes to add to the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.push_back(1);
+    /// list.reserve(2);
+    /// list.push_back(2); // no allocation
+    /// list.push_back(3); // no allocation
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(n)             |
+    pub fn reserve(&mut self, additional: usize) {
+        let pool = self.pool.get_or_insert_with(Vec::new);
+        pool.reserve(additional);
+        for _ in 0..additional {
+            // The value slot stays uninitialized until `alloc_node` writes a whole node over it.
+            let node = Box::into_raw(Box::new(MaybeUninit::<LinkedListNode<T>>::uninit()));
+            pool.push(unsafe { NonNull::new_unchecked(node as *mut LinkedListNode<T>) });
+        }
+    }
+
+    /// Frees the pooled nodes and empties `pool`.
+    ///
+    /// Pooled nodes no longer hold a value, so they are freed as `MaybeUninit` to release the memory
+    /// without dropping the (already moved-out) value a second time.
+    fn free_pool(pool: &mut Vec<NonNull<LinkedListNode<T>>>) {
+        for node in pool.drain(..) {
+            unsafe {
+                let _ = Box::from_raw(node.as_ptr() as *mut MaybeUninit<LinkedListNode<T>>);
+            }
+        }
+    }
+
+    /// Returns a node holding `val`, reusing a pooled node if there is one.
+    fn alloc_node(&mut self, val: T) -> NonNull<LinkedListNode<T>> {
+        match self.pool.as_mut().and_then(Vec::pop) {
+            Some(node_ptr) => {
+                // The pooled node's value slot is uninitialized, so write the whole node without reading it.
+                unsafe { node_ptr.as_ptr().write(LinkedListNode::new(val)) };
+                node_ptr
+            }
+            None => NonNull::from(Box::leak(Box::new(LinkedListNode::new(val)))),
+        }
+    }
+
+    /// Moves the value out of a node that is no longer linked, then frees the node or keeps it in the pool.
+    ///
+    /// # Safety
+    ///
+    /// `node_ptr` must be a live node allocated by `alloc_node` that is no longer reachable from the list.
+    unsafe fn free_node(&mut self, node_ptr: NonNull<LinkedListNode<T>>) -> T {
+        match self.pool.as_mut() {
+            Some(pool) => {
+                let node = node_ptr.as_ptr().read();
+                pool.push(node_ptr);
+                node.value
+            }
+            None => Box::from_raw(node_ptr.as_ptr()).value,
+        }
+    }
+
     /// Inserts a new node with the given value at the beginning of the list.
     ///
     /// # Arguments
@@ -115,7 +291,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list = LinkedList::new();
     /// list.push_head(1);
@@ -130,19 +306,17 @@ impl<T> LinkedList<T> {
     /// |-----------------|------------------|
     /// | O(1)            | O(1)             |
     pub fn push_head(&mut self, val: T) {
-        let mut node = Box::new(LinkedListNode::new(val));
-        node.next = self.head;
-        let node_ptr = NonNull::new(Box::into_raw(node));
+        let node_ptr = self.alloc_node(val);
 
         if let Some(old_head) = self.head {
             unsafe {
-                (*node_ptr.unwrap().as_ptr()).next = Some(old_head);
+                (*node_ptr.as_ptr()).next = Some(old_head);
             }
         } else {
-            self.tail = node_ptr;
+            self.tail = Some(node_ptr);
         }
 
-        self.head = node_ptr;
+        self.head = Some(node_ptr);
         self.len += 1;
     }
 
@@ -155,7 +329,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list = LinkedList::new();
     /// list.push_back(1);
@@ -170,8 +344,7 @@ impl<T> LinkedList<T> {
     /// |-----------------|------------------|
     /// | O(1)            | O(1)             |
     pub fn push_back(&mut self, val: T) {
-        let node = Box::new(LinkedListNode::new(val));
-        let node_ptr = NonNull::new(Box::into_raw(node));
+        let node_ptr = Some(self.alloc_node(val));
 
         unsafe {
             if let Some(tail) = self.tail {
@@ -199,14 +372,14 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
-    /// assert_eq!(list.pop_head(), Err(hym::LinkedListError::PopFromEmptyList));
+    /// assert_eq!(list.pop_head(), Err(hym::nonull_linked_list::LinkedListError::PopFromEmptyList));
     /// ```
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_head(1);
@@ -225,8 +398,7 @@ impl<T> LinkedList<T> {
     pub fn pop_head(&mut self) -> Result<T, LinkedListError> {
         match self.head {
             Some(head_ptr) => unsafe {
-                let head = Box::from_raw(head_ptr.as_ptr());
-                self.head = head.next;
+                self.head = head_ptr.as_ref().next;
 
                 // If the list becomes empty, update the tail.
                 if self.head.is_none() {
@@ -234,7 +406,7 @@ impl<T> LinkedList<T> {
                 }
 
                 self.len -= 1;
-                Ok(head.value)
+                Ok(self.free_node(head_ptr))
             },
             None => Err(LinkedListError::PopFromEmptyList),
         }
@@ -250,7 +422,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::{LinkedList, LinkedListError};
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -275,10 +447,7 @@ impl<T> LinkedList<T> {
             let head_ptr = self.head.take().unwrap();
             self.tail = None;
             self.len = 0;
-            unsafe {
-                let head = Box::from_raw(head_ptr.as_ptr());
-                Ok(head.value)
-            }
+            unsafe { Ok(self.free_node(head_ptr)) }
         } else {
             // Traverse to the second-to-last node.
             let mut current = self.head;
@@ -292,8 +461,7 @@ impl<T> LinkedList<T> {
                 let tail_ptr = current.unwrap().as_mut().next.take().unwrap();
                 self.tail = current;
                 self.len -= 1;
-                let tail = Box::from_raw(tail_ptr.as_ptr());
-                Ok(tail.value)
+                Ok(self.free_node(tail_ptr))
             }
         }
     }
@@ -313,7 +481,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -345,11 +513,10 @@ impl<T> LinkedList<T> {
                 }
             }
 
+            let mut node_ptr = self.alloc_node(val);
             unsafe {
-                let node = Box::new(LinkedListNode::new(val));
-                let node_ptr = NonNull::new(Box::into_raw(node));
-                node_ptr.unwrap().as_mut().next = current.unwrap().as_ref().next;
-                current.unwrap().as_mut().next = node_ptr;
+                node_ptr.as_mut().next = current.unwrap().as_ref().next;
+                current.unwrap().as_mut().next = Some(node_ptr);
             }
 
             self.len += 1;
@@ -372,7 +539,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -388,6 +555,10 @@ impl<T> LinkedList<T> {
     /// |-----------------|------------------|
     /// | O(n)            | O(1)             |
     pub fn remove(&mut self, at: usize) -> Result<T, LinkedListError> {
+        if self.len == 0 {
+            return Err(LinkedListError::RemoveFromEmptyList);
+        }
+
         if at >= self.len {
             return Err(LinkedListError::RemoveOutOfRange);
         }
@@ -411,7 +582,7 @@ impl<T> LinkedList<T> {
                 }
 
                 self.len -= 1;
-                Ok(Box::from_raw(node_to_remove.as_ptr()).value)
+                Ok(self.free_node(node_to_remove))
             }
         }
     }
@@ -429,7 +600,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -478,7 +649,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -520,7 +691,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -541,7 +712,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// assert!(list.is_empty());
@@ -554,10 +725,12 @@ impl<T> LinkedList<T> {
 
     /// Clears the list by removing all nodes.
     ///
+    /// For a list created with `with_pool`, the removed nodes are kept for reuse instead of being freed.
+    ///
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::new();
     /// list.push_back(1);
@@ -574,7 +747,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
     /// let mut iter = list.iter();
@@ -583,7 +756,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(iter.next(), Some(&3));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> LinkedListBorrowIterator<T> {
+    pub fn iter(&self) -> LinkedListBorrowIterator<'_, T> {
         LinkedListBorrowIterator::new(self.head)
     }
 
@@ -592,7 +765,7 @@ impl<T> LinkedList<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
     /// let mut iter = list.iter_mut(); // Create a mutable borrowed iterator for the linked list.
@@ -604,7 +777,7 @@ impl<T> LinkedList<T> {
     /// assert_eq!(iter.next(), Some(&mut 5));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter_mut(&mut self) -> LinkedListBorrowMutIterator<T> {
+    pub fn iter_mut(&mut self) -> LinkedListBorrowMutIterator<'_, T> {
         LinkedListBorrowMutIterator::new(self.head)
     }
 
@@ -626,137 +799,763 @@ impl<T> LinkedList<T> {
         unsafe { Some(current.unwrap().as_ref().value.clone()) }
     }
 
-    /// Creates a `LinkedList` from an iterator.
+    /// Counts the occurrences of a given value in the list.
+    ///
+    /// Unlike `val2ix(&val).len()`, this allocates nothing.
     ///
     /// # Arguments
     ///
-    /// * `iter` - An iterator over values of type `T`.
+    /// * `val` - The value to count.
     ///
     /// # Returns
     ///
-    /// * `Self` - A new `LinkedList` containing the values from the iterator.
+    /// * `usize` - The number of elements equal to `val`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use hym::nonnull_linked_list::LinkedList;
+    /// use hym::nonull_linked_list::LinkedList;
     ///
-    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
-    /// assert_eq!(list.len(), 3);
-    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1, 3, 1]);
+    /// assert_eq!(list.count(&1), 3);
+    /// assert_eq!(list.count(&4), 0);
     /// ```
-    pub fn from_iter<I>(iter: I) -> Self
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    pub fn count(&self, val: &T) -> usize
     where
-        I: IntoIterator<Item = T>,
+        T: PartialEq,
     {
-        let mut list = LinkedList::new();
-        for item in iter {
-            list.push_back(item);
-        }
-        list
-    }
-}
-
-impl<T: Clone> Clone for LinkedList<T> {
-    fn clone(&self) -> Self {
-        let mut new_list = LinkedList::new();
-        for item in self.iter() {
-            new_list.push_back(item.clone());
-        }
-        new_list
-    }
-}
-
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        let mut current = self.head;
-        while let Some(node) = current {
-            unsafe {
-                let next = node.as_ref().next;
-                let _ = Box::from_raw(node.as_ptr());
-                current = next;
-            }
-        }
-    }
-}
-
-impl<T: fmt::Display> fmt::Display for LinkedList<T> {
-    /// Formats the list as a string.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "(")?;
+        let mut count = 0;
         let mut current = self.head;
-        let mut first = true;
 
-        while let Some(node_ptr) = current {
+        while let Some(node) = current {
             unsafe {
-                if !first {
-                    write!(f, " -> ")?;
+                if node.as_ref().value == *val {
+                    count += 1;
                 }
-                write!(f, "{}", node_ptr.as_ref().value)?;
-                first = false;
-                current = node_ptr.as_ref().next;
+                current = node.as_ref().next;
             }
         }
 
-        write!(f, ")")
+        count
     }
-}
 
-/// Iterator for `LinkedList<T>`.
-pub struct LinkedListIterator<T> {
-    current: Option<NonNull<LinkedListNode<T>>>,
-    _marker: PhantomData<T>, // Ensures the iterator is tied to the list's lifetime.
-}
-
-impl<T> LinkedListIterator<T> {
-    /// Creates a new `LinkedListIterator` starting at the given node.
-    fn new(head: Option<NonNull<LinkedListNode<T>>>) -> Self {
-        Self {
-            current: head,
-            _marker: PhantomData,
-        }
+    /// Returns a reference to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the head value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.first(), Some(&1));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn first(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
     }
-}
-
-impl<T: Clone> Iterator for LinkedListIterator<T> {
-    type Item = T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.current.map(|node| unsafe {
-            let node_ref = node.as_ref();
-            self.current = node_ref.next;
-            node_ref.value.clone()
-        })
+    /// Returns a reference to the last element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - A reference to the tail value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.last(), Some(&3));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn last(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
     }
-}
-
-impl<T: Clone> IntoIterator for LinkedList<T> {
-    type Item = T;
-    type IntoIter = LinkedListIterator<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        LinkedListIterator::new(self.head)
+    /// Returns a mutable reference to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` - A mutable reference to the head value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// *list.first_mut().unwrap() = 10;
+    /// assert_eq!(format!("{}", list), "(10 -> 2 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        // The reference borrows `self` mutably, so nothing else can reach the node while it lives.
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
     }
-}
 
-/// Borrowed iterator for `LinkedList<T>`.
-pub struct LinkedListBorrowIterator<'a, T> {
-    current: Option<NonNull<LinkedListNode<T>>>,
-    _marker: PhantomData<&'a T>, // Ensures the iterator is tied to the list's lifetime.
-}
-
-impl<'a, T> LinkedListBorrowIterator<'a, T> {
-    /// Creates a new `LinkedListBorrowIterator` starting at the given node.
-    fn new(head: Option<NonNull<LinkedListNode<T>>>) -> Self {
-        Self {
-            current: head,
-            _marker: PhantomData,
-        }
+    /// Returns a mutable reference to the last element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` - A mutable reference to the tail value.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// *list.last_mut().unwrap() = 30;
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 30)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
     }
-}
-
-impl<'a, T> Iterator for LinkedListBorrowIterator<'a, T> {
-    type Item = &'a T;
+
+    /// Returns a raw pointer to the first node, for code that walks the nodes itself (e.g. over FFI).
+    ///
+    /// The chain can be followed read-only with `LinkedListNode::next_ptr` and read with
+    /// `LinkedListNode::value`.
+    ///
+    /// # Safety
+    ///
+    /// Getting the pointer is safe, dereferencing it is not. The pointers stay valid only while the
+    /// list is alive and not modified: any `&mut self` method (pushes, pops, `clean`, ...) may free
+    /// or relink nodes, and dropping the list frees all of them. Nodes must only be read through
+    /// these pointers, never written, and never freed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(NonNull<LinkedListNode<T>>)` - A pointer to the head node.
+    /// * `None` - If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// let mut values = Vec::new();
+    /// let mut node = list.head_ptr();
+    /// while let Some(ptr) = node {
+    ///     // The list is alive and not modified while the nodes are read.
+    ///     let node_ref = unsafe { ptr.as_ref() };
+    ///     values.push(*node_ref.value());
+    ///     node = node_ref.next_ptr();
+    /// }
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn head_ptr(&self) -> Option<NonNull<LinkedListNode<T>>> {
+        self.head
+    }
+
+    /// Swaps the values of the first and last elements. The nodes themselves stay where they are.
+    ///
+    /// Does nothing if the list has fewer than two elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// list.swap_ends();
+    /// assert_eq!(format!("{}", list), "(4 -> 2 -> 3 -> 1)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(1)            | O(1)             |
+    pub fn swap_ends(&mut self) {
+        if let (Some(head), Some(tail)) = (self.head, self.tail) {
+            if head != tail {
+                // Two distinct nodes, so the two mutable references do not alias.
+                unsafe {
+                    std::mem::swap(&mut (*head.as_ptr()).value, &mut (*tail.as_ptr()).value);
+                }
+            }
+        }
+    }
+
+    /// Removes and returns the value at a specific index, replacing it with the last value.
+    ///
+    /// Like `Vec::swap_remove`, this does not preserve the order of the list. The list is singly
+    /// linked, so unlinking the tail still walks to the second-to-last node; what this saves over
+    /// `remove` is relinking in the middle of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index of the value to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The value at the specified index.
+    /// * `Err(LinkedListError)` - If the list is empty or the index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// assert_eq!(list.swap_remove(1), Ok(2));
+    /// assert_eq!(format!("{}", list), "(1 -> 4 -> 3)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    pub fn swap_remove(&mut self, at: usize) -> Result<T, LinkedListError> {
+        if self.len == 0 {
+            return Err(LinkedListError::RemoveFromEmptyList);
+        }
+
+        if at >= self.len {
+            return Err(LinkedListError::RemoveOutOfRange);
+        }
+
+        let mut current = self.head;
+        for _ in 0..at {
+            unsafe {
+                current = current.unwrap().as_ref().next;
+            }
+        }
+
+        let (target, tail) = (current.unwrap(), self.tail.unwrap());
+        if target != tail {
+            // Move the tail value into the removed slot; the removed value ends up in the tail node.
+            unsafe { std::ptr::swap(&mut (*target.as_ptr()).value, &mut (*tail.as_ptr()).value) };
+        }
+
+        self.pop_back()
+    }
+
+    /// Keeps only the elements for which the predicate returns `true`, in their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Returns `true` for the elements to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(1..=6);
+    /// list.retain(|x| x % 2 == 0);
+    /// assert_eq!(format!("{}", list), "(2 -> 4 -> 6)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut prev: Option<NonNull<LinkedListNode<T>>> = None;
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            unsafe {
+                let next = node.as_ref().next;
+                if f(&node.as_ref().value) {
+                    prev = Some(node);
+                } else {
+                    // Unlink the node first, so the list stays valid even if dropping the value panics.
+                    match prev {
+                        Some(mut prev) => prev.as_mut().next = next,
+                        None => self.head = next,
+                    }
+                    if next.is_none() {
+                        self.tail = prev;
+                    }
+                    self.len -= 1;
+                    drop(self.free_node(node));
+                }
+                current = next;
+            }
+        }
+    }
+
+    /// Returns mutable references to the values at two distinct indices at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the first value.
+    /// * `j` - The index of the second value.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&mut T, &mut T))` - The values at `i` and `j`, in that order.
+    /// * `None` - If `i == j` or either index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// if let Some((a, b)) = list.get_pair_mut(0, 2) {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// assert_eq!(format!("{}", list), "(3 -> 2 -> 1)");
+    /// assert!(list.get_pair_mut(1, 1).is_none());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    pub fn get_pair_mut(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+        if i == j || i >= self.len || j >= self.len {
+            return None;
+        }
+
+        let (mut first, mut second) = (None, None);
+        let mut current = self.head;
+        for ix in 0..=i.max(j) {
+            let node = current.unwrap();
+            if ix == i {
+                first = Some(node);
+            }
+            if ix == j {
+                second = Some(node);
+            }
+            unsafe {
+                current = node.as_ref().next;
+            }
+        }
+
+        // `i != j`, so the two pointers are different nodes and the references never alias. Both
+        // borrow from `&mut self`, so the list cannot be touched while either one is alive.
+        unsafe {
+            Some((
+                &mut (*first.unwrap().as_ptr()).value,
+                &mut (*second.unwrap().as_ptr()).value,
+            ))
+        }
+    }
+
+    /// Frees every node held in the pool, returning that memory to the allocator.
+    ///
+    /// Live nodes are not touched and the list stays in pooled mode, so nodes removed from now on
+    /// are pooled again. This trades the speed of the next pushes, which have to allocate again,
+    /// for lower memory use. Does nothing for a list without a pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::with_capacity(1000);
+    /// list.push_back(1);
+    /// list.shrink_to_fit(); // frees the 999 unused nodes
+    /// assert_eq!(format!("{}", list), "(1)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(k)            | O(1)             |
+    pub fn shrink_to_fit(&mut self) {
+        if let Some(pool) = self.pool.as_mut() {
+            Self::free_pool(pool);
+            pool.shrink_to_fit();
+        }
+    }
+
+    /// Splits the list in two at the given index.
+    ///
+    /// `self` keeps the elements `[0, at)` and the returned list gets `[at, len)`. The nodes are
+    /// relinked, not copied: `self` ends at the node before `at`, and the new list starts at node
+    /// `at` and ends at the old tail. A pooled list returns a pooled list, with an empty pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index of the first element of the new list.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LinkedList<T>)` - The elements from `at` onwards.
+    /// * `Err(LinkedListError)` - If `at` is greater than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+    /// let back = list.split_off(1).unwrap();
+    /// assert_eq!(format!("{}", list), "(1)");
+    /// assert_eq!(format!("{}", back), "(2 -> 3 -> 4)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    pub fn split_off(&mut self, at: usize) -> Result<LinkedList<T>, LinkedListError> {
+        if at > self.len {
+            return Err(LinkedListError::InsertOutOfRange);
+        }
+
+        let mut other = if self.pool.is_some() {
+            LinkedList::with_pool()
+        } else {
+            LinkedList::new()
+        };
+        if at == self.len {
+            return Ok(other);
+        }
+
+        if at == 0 {
+            other.head = self.head.take();
+            other.tail = self.tail.take();
+        } else {
+            // `at < len`, so the node before `at` and the one after it both exist.
+            let mut last = self.head.unwrap();
+            for _ in 0..at - 1 {
+                last = unsafe { last.as_ref().next.unwrap() };
+            }
+            other.head = unsafe { last.as_mut().next.take() };
+            other.tail = self.tail.replace(last);
+        }
+
+        other.len = self.len - at;
+        self.len = at;
+        Ok(other)
+    }
+
+    /// Checks whether following the `next` pointers from `head` ever comes back to a node.
+    ///
+    /// A well-formed list never has a cycle; one can only appear through a bug in unsafe code that
+    /// relinks nodes. Uses Floyd's tortoise and hare, so it terminates in O(n) either way, unlike
+    /// loops over the list that trust `len` or wait for a `None`.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the nodes form a cycle.
+    /// * `false` - If the walk reaches the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert!(!list.has_cycle());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time Complexity | Space Complexity |
+    /// |-----------------|------------------|
+    /// | O(n)            | O(1)             |
+    pub fn has_cycle(&self) -> bool {
+        let next = |node: NonNull<LinkedListNode<T>>| unsafe { node.as_ref().next };
+
+        let (mut slow, mut fast) = (self.head, self.head);
+        while let Some(two_ahead) = fast.and_then(next).and_then(next) {
+            slow = slow.and_then(next);
+            fast = Some(two_ahead);
+            if slow == fast {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Panics in debug builds if the nodes form a cycle, see `has_cycle`.
+    ///
+    /// A hook to call by hand while debugging code that relinks nodes. Nothing calls it on its own:
+    /// the check walks the whole list, which would double the cost of a drop.
+    fn debug_assert_acyclic(&self) {
+        debug_assert!(!self.has_cycle(), "cycle in the nodes of a LinkedList");
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    /// Creates a `LinkedList` from an iterator.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - An iterator over values of type `T`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - A new `LinkedList` containing the values from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::nonull_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        for item in iter {
+            list.push_back(item);
+        }
+        list
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        // A clone of a pooled list is pooled as well, but starts with an empty pool.
+        let mut new_list = if self.pool.is_some() {
+            LinkedList::with_pool()
+        } else {
+            LinkedList::new()
+        };
+        for item in self.iter() {
+            new_list.push_back(item.clone());
+        }
+        new_list
+    }
+}
+
+/// Hashes the length, then every element from head to tail, the same as the box list.
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        let mut current = self.head;
+        while let Some(node) = current {
+            unsafe {
+                node.as_ref().value.hash(state);
+                current = node.as_ref().next;
+            }
+        }
+    }
+}
+
+// The list owns every node it points to: nodes are only created by the list, freed by the list, and
+// never shared with another list. Sending or sharing a `LinkedList<T>` is therefore the same as
+// sending or sharing the `T`s it holds (just like `Box<T>`), so these bounds mirror
+// `std::collections::LinkedList`.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T> Drop for LinkedList<T> {
+    /// Frees the live nodes together with their values, then the pooled nodes.
+    fn drop(&mut self) {
+        let mut current = self.head;
+        while let Some(node) = current {
+            unsafe {
+                let next = node.as_ref().next;
+                let _ = Box::from_raw(node.as_ptr());
+                current = next;
+            }
+        }
+
+        if let Some(pool) = self.pool.as_mut() {
+            Self::free_pool(pool);
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    /// Formats the list as a string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        let mut current = self.head;
+        let mut first = true;
+
+        while let Some(node_ptr) = current {
+            unsafe {
+                if !first {
+                    write!(f, " -> ")?;
+                }
+                write!(f, "{}", node_ptr.as_ref().value)?;
+                first = false;
+                current = node_ptr.as_ref().next;
+            }
+        }
+
+        write!(f, ")")
+    }
+}
+
+/// Iterator for `LinkedList<T>`.
+///
+/// Yields from both ends. The `len` nodes starting at `front` belong to the iterator, and each one
+/// is freed as it is yielded, from whichever end reaches it first.
+///
+/// The nodes only link forwards, so the first `next_back` collects pointers to the remaining nodes
+/// into `back`, and later calls pop from it. Nodes yielded by `next` stay at the bottom of `back`
+/// but are never read again: only the top `len` entries are live.
+pub struct LinkedListIterator<T> {
+    front: Option<NonNull<LinkedListNode<T>>>,
+    back: Vec<NonNull<LinkedListNode<T>>>,
+    len: usize,
+    _marker: PhantomData<T>, // Ensures the iterator is tied to the list's lifetime.
+}
+
+impl<T> LinkedListIterator<T> {
+    /// Creates a new `LinkedListIterator` over the `len` nodes starting at `head`.
+    fn new(head: Option<NonNull<LinkedListNode<T>>>, len: usize) -> Self {
+        Self {
+            front: head,
+            back: Vec::new(),
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for LinkedListIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        self.front.take().map(|node| unsafe {
+            let node = Box::from_raw(node.as_ptr());
+            if self.len > 0 {
+                self.front = node.next;
+            }
+            node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for LinkedListIterator<T> {
+    /// Yields the last remaining value.
+    ///
+    /// The first call walks the remaining nodes once to collect them, O(n) time and memory; every
+    /// later call is O(1), so draining the iterator from the back is O(n) overall.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        if self.back.is_empty() {
+            self.back.reserve_exact(self.len);
+            let mut current = self.front;
+            for _ in 0..self.len {
+                let node = current.unwrap();
+                self.back.push(node);
+                current = unsafe { node.as_ref().next };
+            }
+        }
+
+        self.len -= 1;
+        if self.len == 0 {
+            self.front = None; // That was the last node, which `front` points to as well.
+        }
+        let node = self.back.pop().unwrap();
+        unsafe { Some(Box::from_raw(node.as_ptr()).value) }
+    }
+}
+
+impl<T> Drop for LinkedListIterator<T> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = LinkedListIterator<T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        // Hand the nodes over to the iterator and leave `self` empty, so its `Drop` frees nothing.
+        self.tail = None;
+        let iter = LinkedListIterator::new(self.head.take(), self.len);
+        self.len = 0;
+        iter
+    }
+}
+
+/// Borrowed iterator for `LinkedList<T>`.
+pub struct LinkedListBorrowIterator<'a, T> {
+    current: Option<NonNull<LinkedListNode<T>>>,
+    _marker: PhantomData<&'a T>, // Ensures the iterator is tied to the list's lifetime.
+}
+
+impl<'a, T> LinkedListBorrowIterator<'a, T> {
+    /// Creates a new `LinkedListBorrowIterator` starting at the given node.
+    fn new(head: Option<NonNull<LinkedListNode<T>>>) -> Self {
+        Self {
+            current: head,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// Not derived: the derive would require `T: Clone`. The iterator only reads through the pointer, so
+// two copies walking the same list are as fine as two shared references.
+impl<'a, T> Clone for LinkedListBorrowIterator<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LinkedListBorrowIterator<'a, T> {
+    type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.current.map(|node| unsafe {
@@ -798,10 +1597,39 @@ impl<'a, T> Iterator for LinkedListBorrowMutIterator<'a, T> {
 // Unit Test for LinkedList
 #[cfg(test)]
 mod tests {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
     use std::vec;
 
     use super::*;
 
+    /// The system allocator, counting the allocations made by each thread so that tests running in
+    /// parallel do not show up in each other's counts.
+    struct CountingAlloc;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1)); // not counted during thread teardown
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    /// Returns how many allocations the current thread has made so far.
+    fn allocations() -> usize {
+        ALLOCATIONS.with(Cell::get)
+    }
+
     #[test]
     fn test_push_head() {
         // Test adding elements to the head of the list
@@ -1056,6 +1884,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_from_iter() {
         // Test creating a list from a vector
         let list: LinkedList<i32> = LinkedList::from_iter(vec![]);
@@ -1125,4 +1954,482 @@ mod tests {
 
         assert_eq!(format!("{}", list), "(1 -> 4 -> 9 -> 16 -> 25)");
     }
+
+    #[test]
+    fn test_count() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.count(&1), 0); // Empty list
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(3);
+        list.push_back(1);
+        assert_eq!(list.count(&1), 3); // 1 appears three times
+        assert_eq!(list.count(&2), 1);
+        assert_eq!(list.count(&4), 0); // No 4 in the list
+        assert_eq!(list.count(&1), list.val2ix(&1).len());
+    }
+
+    #[test]
+    fn test_send_to_thread() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        // Move the list into another thread and send its elements back
+        let handle = std::thread::spawn(move || {
+            for val in list.iter() {
+                tx.send(*val).unwrap();
+            }
+        });
+        handle.join().unwrap();
+
+        assert_eq!(rx.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_share_between_threads() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+
+        // Read the same list from two threads at once
+        std::thread::scope(|s| {
+            let a = s.spawn(|| list.iter().sum::<i32>());
+            let b = s.spawn(|| list.len());
+            assert_eq!(a.join().unwrap(), 6);
+            assert_eq!(b.join().unwrap(), 3);
+        });
+    }
+
+    #[test]
+    fn test_with_pool_refill() {
+        let mut list = LinkedList::with_pool();
+        for i in 0..5 {
+            list.push_back(i.to_string());
+        }
+
+        // clean keeps every node in the pool
+        list.clean();
+        assert!(list.is_empty());
+        assert_eq!(list.pool.as_ref().unwrap().len(), 5);
+
+        // refilling takes the nodes back out of the pool
+        list.push_head("b".to_string());
+        list.push_back("c".to_string());
+        list.insert("a".to_string(), 0).unwrap();
+        assert_eq!(list.pool.as_ref().unwrap().len(), 2);
+        assert_eq!(format!("{}", list), "(a -> b -> c)");
+
+        // pop_back and remove recycle too
+        assert_eq!(list.pop_back(), Ok("c".to_string()));
+        assert_eq!(list.remove(1), Ok("b".to_string()));
+        assert_eq!(list.pool.as_ref().unwrap().len(), 4);
+        assert_eq!(format!("{}", list), "(a)");
+    }
+
+    #[test]
+    fn test_with_pool_clone_and_into_iter() {
+        let mut list = LinkedList::with_pool();
+        list.push_back(vec![1]);
+        list.push_back(vec![2]);
+        list.pop_head().unwrap();
+
+        // the clone is pooled but has nothing pooled yet
+        let cloned = list.clone();
+        assert_eq!(cloned.pool.as_ref().unwrap().len(), 0);
+
+        // consuming a pooled list frees both the live and the pooled nodes
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![vec![2]]);
+        assert_eq!(cloned.into_iter().collect::<Vec<_>>(), vec![vec![2]]);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut list = LinkedList::with_capacity(2);
+        list.reserve(2);
+        let reserved = list.pool.clone().unwrap();
+        assert_eq!(reserved.len(), 4);
+
+        // Exactly the reserved number of pushes empties the pool without growing it
+        list.push_back("b".to_string());
+        list.push_head("a".to_string());
+        list.insert("c".to_string(), 2).unwrap();
+        list.push_back("d".to_string());
+        assert_eq!(list.pool.as_ref().unwrap().len(), 0);
+        assert_eq!(list.pool.as_ref().unwrap().capacity(), 4);
+        assert_eq!(format!("{}", list), "(a -> b -> c -> d)");
+
+        // Every node in the list is one of the reserved ones
+        let mut current = list.head;
+        while let Some(node) = current {
+            assert!(reserved.contains(&node));
+            current = unsafe { node.as_ref().next };
+        }
+
+        // reserve switches a plain list to pooled mode
+        let mut plain: LinkedList<String> = LinkedList::new();
+        plain.reserve(1);
+        assert_eq!(plain.pool.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_reserve_does_not_allocate() {
+        let mut list = LinkedList::new();
+        list.reserve(100);
+        let before = allocations();
+        for i in 0..50 {
+            list.push_back(i);
+            list.push_head(i);
+        }
+        assert_eq!(allocations(), before);
+
+        // Once the pool is empty, pushes allocate again
+        list.push_back(100);
+        assert_eq!(allocations(), before + 1);
+
+        // Removed nodes go back to the pool of the now pooled list, and are reused
+        list.clean();
+        let before = allocations();
+        for i in 0..101 {
+            list.push_back(i);
+        }
+        assert_eq!(allocations(), before);
+    }
+
+    #[test]
+    fn test_swap_remove() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+
+        // Removing from the middle moves the former tail into the gap
+        assert_eq!(list.swap_remove(1), Ok(2));
+        assert_eq!(format!("{}", list), "(1 -> 5 -> 3 -> 4)");
+        assert_eq!(list.last(), Some(&4));
+
+        // Removing the tail itself just pops it
+        assert_eq!(list.swap_remove(3), Ok(4));
+        assert_eq!(format!("{}", list), "(1 -> 5 -> 3)");
+
+        // Removing the head
+        assert_eq!(list.swap_remove(0), Ok(1));
+        assert_eq!(format!("{}", list), "(3 -> 5)");
+        assert_eq!(list.len(), 2);
+
+        assert_eq!(list.swap_remove(2), Err(LinkedListError::RemoveOutOfRange));
+        list.clean();
+        assert_eq!(
+            list.swap_remove(0),
+            Err(LinkedListError::RemoveFromEmptyList)
+        );
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=6);
+        list.retain(|x| x % 2 == 0); // Drops the head and the tail
+        assert_eq!(format!("{}", list), "(2 -> 4 -> 6)");
+        assert_eq!(list.len(), 3);
+        list.push_back(8); // The tail pointer must still be valid
+        assert_eq!(format!("{}", list), "(2 -> 4 -> 6 -> 8)");
+
+        list.retain(|&x| x < 6); // Drops the last two nodes
+        assert_eq!(list.last(), Some(&4));
+        list.push_back(5);
+        assert_eq!(format!("{}", list), "(2 -> 4 -> 5)");
+
+        list.retain(|_| false); // Drops everything
+        assert!(list.is_empty());
+        assert!(list.head.is_none() && list.tail.is_none());
+        list.push_back(1);
+        assert_eq!(format!("{}", list), "(1)");
+        assert_eq!(list.first(), list.last());
+    }
+
+    #[test]
+    fn test_retain_pooled() {
+        // Rejected nodes go back to the pool instead of being freed
+        let mut list = LinkedList::with_pool();
+        for i in 1..=4 {
+            list.push_back(i.to_string());
+        }
+        list.retain(|s| s != "2" && s != "4");
+        assert_eq!(list.pool.as_ref().unwrap().len(), 2);
+        assert_eq!(format!("{}", list), "(1 -> 3)");
+    }
+
+    #[test]
+    fn test_get_pair_mut() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+
+        // A valid pair, in either order
+        let (a, b) = list.get_pair_mut(3, 1).unwrap();
+        assert_eq!((*a, *b), (4, 2));
+        *a += 10;
+        *b += 20;
+        assert_eq!(format!("{}", list), "(1 -> 22 -> 3 -> 14)");
+
+        assert!(list.get_pair_mut(2, 2).is_none()); // Same index
+        assert!(list.get_pair_mut(0, 4).is_none()); // Out of range
+        assert!(list.get_pair_mut(4, 0).is_none());
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+        let mut iter = list.iter();
+        iter.next();
+
+        // The clone starts where the original is and then moves on its own
+        let mut clone = iter.clone();
+        assert_eq!(clone.next(), Some(&2));
+        assert_eq!(clone.next(), Some(&3));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(clone.next(), Some(&4));
+        assert_eq!(clone.next(), None);
+    }
+
+    #[test]
+    fn test_default() {
+        let list: LinkedList<i32> = LinkedList::default();
+        assert!(list.is_empty());
+
+        // mem::take leaves an empty list behind
+        let mut list = LinkedList::from_iter(vec![1, 2]);
+        let taken = std::mem::take(&mut list);
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", taken), "(1 -> 2)");
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut list = LinkedList::with_pool();
+        for round in 0..10 {
+            for i in 0..50 {
+                list.push_back(round * 50 + i);
+            }
+            list.retain(|x| x % 10 == 0); // Keep a few, pool the rest
+        }
+        let live = list.iter().copied().collect::<Vec<_>>();
+        assert!(!list.pool.as_ref().unwrap().is_empty());
+
+        list.shrink_to_fit();
+        assert!(list.pool.as_ref().unwrap().is_empty());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), live); // Live nodes untouched
+        assert_eq!(list.len(), 50);
+
+        // Still pooled afterwards
+        list.pop_head().unwrap();
+        assert_eq!(list.pool.as_ref().unwrap().len(), 1);
+        list.push_back(-1);
+        assert_eq!(list.last(), Some(&-1));
+
+        // No-op without a pool
+        let mut plain = LinkedList::from_iter(vec![1, 2]);
+        plain.shrink_to_fit();
+        assert!(plain.pool.is_none());
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(1..=5);
+        assert!(!list.has_cycle());
+        assert!(!LinkedList::<i32>::new().has_cycle());
+
+        // Forge a cycle by pointing the tail back at the second node
+        let (head, tail) = (list.head.unwrap(), list.tail.unwrap());
+        unsafe {
+            (*tail.as_ptr()).next = head.as_ref().next;
+        }
+        assert!(list.has_cycle());
+
+        // A single node pointing at itself
+        unsafe {
+            (*tail.as_ptr()).next = Some(tail);
+        }
+        assert!(list.has_cycle());
+
+        // Undo it before the list is dropped
+        unsafe {
+            (*tail.as_ptr()).next = None;
+        }
+        assert!(!list.has_cycle());
+        list.push_back(6);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5 -> 6)");
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        // Alternate between the two ends until they meet
+        let list: LinkedList<String> = (1..=5).map(|i| i.to_string()).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        assert_eq!(iter.next().as_deref(), Some("1"));
+        assert_eq!(iter.next_back().as_deref(), Some("5"));
+        assert_eq!(iter.next().as_deref(), Some("2"));
+        assert_eq!(iter.next_back().as_deref(), Some("4"));
+        assert_eq!(iter.next_back().as_deref(), Some("3")); // The middle node, freed once
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // rev() and dropping a half-consumed iterator
+        let list: LinkedList<i32> = LinkedList::from_iter(1..=4);
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        let list: LinkedList<Vec<i32>> = (1..=4).map(|i| vec![i]).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back(), Some(vec![4]));
+        drop(iter);
+
+        // Switching ends after the back was collected
+        let mut iter = LinkedList::from_iter(1..=6).into_iter();
+        assert_eq!(iter.next_back(), Some(6));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_into_iter_rev_long_list() {
+        // Draining from the back is linear overall, not one walk per element
+        let list: LinkedList<u32> = (0..100_000).collect();
+        let mut expected = 100_000;
+        for val in list.into_iter().rev() {
+            expected -= 1;
+            assert_eq!(val, expected);
+        }
+        assert_eq!(expected, 0);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: LinkedList<i32> = [1, 2, 3].into_iter().collect();
+        let b: LinkedList<i32> = [1, 2, 3].into_iter().collect();
+        let c: LinkedList<i32> = [3, 2, 1].into_iter().collect();
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(hash_of(&a), hash_of(&c));
+    }
+
+    #[test]
+    fn test_first_last_mut() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.first_mut(), None);
+        assert_eq!(list.last_mut(), None);
+
+        // Head and tail are the same node
+        list.push_back(1);
+        *list.first_mut().unwrap() += 1;
+        *list.last_mut().unwrap() *= 10;
+        assert_eq!(format!("{}", list), "(20)");
+
+        list.push_back(2);
+        list.push_back(3);
+        *list.first_mut().unwrap() = 0;
+        *list.last_mut().unwrap() = 4;
+        assert_eq!(format!("{}", list), "(0 -> 2 -> 4)");
+    }
+
+    #[test]
+    fn test_swap_ends() {
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4]);
+        list.swap_ends();
+        assert_eq!(format!("{}", list), "(4 -> 2 -> 3 -> 1)");
+        assert_eq!(list.len(), 4);
+
+        // No-op on a single element and on an empty list
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1]);
+        list.swap_ends();
+        assert_eq!(format!("{}", list), "(1)");
+        assert_eq!(list.len(), 1);
+
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.swap_ends();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_split_off() {
+        // In the middle
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3, 4, 5]);
+        let mut back = list.split_off(2).unwrap();
+        assert_eq!(format!("{}", list), "(1 -> 2)");
+        assert_eq!(format!("{}", back), "(3 -> 4 -> 5)");
+        assert_eq!((list.len(), back.len()), (2, 3));
+
+        // Both tails are valid
+        list.push_back(6);
+        back.push_back(7);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 6)");
+        assert_eq!(format!("{}", back), "(3 -> 4 -> 5 -> 7)");
+        assert_eq!(list.pop_back(), Ok(6));
+        assert_eq!(list.pop_back(), Ok(2));
+        assert_eq!(back.pop_back(), Ok(7));
+        assert_eq!(back.pop_back(), Ok(5));
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(back.last(), Some(&4));
+
+        // At 0, everything moves
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        let mut back = list.split_off(0).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(list.last(), None);
+        list.push_back(3);
+        back.push_back(4);
+        assert_eq!(format!("{}", list), "(3)");
+        assert_eq!(format!("{}", back), "(1 -> 2 -> 4)");
+
+        // At len, nothing moves
+        let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2]);
+        let mut back = list.split_off(2).unwrap();
+        assert!(back.is_empty());
+        back.push_back(3);
+        list.push_back(4);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 4)");
+        assert_eq!(format!("{}", back), "(3)");
+
+        // Out of range
+        assert_eq!(
+            list.split_off(4).err(),
+            Some(LinkedListError::InsertOutOfRange)
+        );
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_split_off_pooled() {
+        // Nodes split off a pooled list are recycled by the new list's own pool
+        let mut list = LinkedList::with_capacity(2);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        let mut back = list.split_off(1).unwrap();
+        assert_eq!(back.pop_head(), Ok(2));
+        back.push_back(4);
+        assert_eq!(format!("{}", back), "(3 -> 4)");
+        assert_eq!(format!("{}", list), "(1)");
+    }
+
+    #[test]
+    fn test_head_ptr() {
+        let list: LinkedList<String> =
+            LinkedList::from_iter(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+
+        // Walk the raw chain and rebuild the sequence
+        let mut values = Vec::new();
+        let mut node = list.head_ptr();
+        while let Some(ptr) = node {
+            let node_ref = unsafe { ptr.as_ref() };
+            values.push(node_ref.value().clone());
+            node = node_ref.next_ptr();
+        }
+        assert_eq!(values, vec!["a", "b", "c"]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.head_ptr().is_none());
+    }
 }
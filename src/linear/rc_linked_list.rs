@@ -1,11 +1,14 @@
 use std::fmt;
+use std::rc::Weak;
 use std::{cell::RefCell, rc::Rc};
 
-/// `LinkedListNode` represents a single node in a linked list containing a value and a reference to the next node.
+/// `LinkedListNode` represents a single node in a linked list containing a value, a reference to the next node
+/// and a weak back-link to the previous node.
 #[derive(Debug, Clone)]
 pub struct LinkedListNode<T> {
-    value: T,                                     // The value stored in the node.
+    value: T,                                       // The value stored in the node.
     next: Option<Rc<RefCell<LinkedListNode<T>>>>, // A reference to the next node in the list, if any.
+    prev: Option<Weak<RefCell<LinkedListNode<T>>>>, // A weak reference to the previous node in the list, if any.
 }
 
 impl<T> LinkedListNode<T>
@@ -34,6 +37,7 @@ where
         LinkedListNode {
             value: val,
             next: next_node,
+            prev: None,
         }
     }
 
@@ -48,6 +52,9 @@ where
 
     /// Inserts a new node with the given value after the current node.
     ///
+    /// The node does not know the `Rc` that owns it, so the `prev` links around the new node are left
+    /// for the caller (the list) to fix up.
+    ///
     /// # Arguments
     ///
     /// * `val` - The value to be inserted after the current node.
@@ -74,6 +81,8 @@ where
 
     /// Removes the next node in the list and returns its value.
     ///
+    /// As with `insert`, the `prev` link of the node following the removed one is left for the caller to fix up.
+    ///
     /// # Returns
     ///
     /// * `Ok(T)` - The value of the removed node.
@@ -123,11 +132,12 @@ impl<T: Default> Default for LinkedListNode<T> {
         LinkedListNode {
             value: T::default(),
             next: None,
+            prev: None,
         }
     }
 }
 
-/// A linked list that supports common operations such as adding and removing elements by Rc + RefCell ptr.
+/// A doubly linked list that supports common operations such as adding and removing elements by Rc + RefCell ptr.
 ///
 /// # Attributes
 ///
@@ -139,6 +149,9 @@ impl<T: Default> Default for LinkedListNode<T> {
 ///
 /// The `LinkedList` struct represents a linked list data structure. It contains the length of the list, a reference to the first node in the list, and a reference to the last node in the list.
 ///
+/// Nodes own their successor through `next` and point back to their predecessor through a `Weak` `prev`
+/// link, so the back-links never form an `Rc` cycle and `pop_back` can unlink the tail in O(1).
+///
 /// The relationship of `self.len` and other values in the struct is as follows:
 ///
 /// ## Case1 `self.len == 0`
@@ -253,7 +266,9 @@ where
             }
             _ => {
                 let node = LinkedListNode::new(val, self.head.clone());
-                self.head = Some(Rc::new(RefCell::new(node)));
+                let node_ptr = Rc::new(RefCell::new(node));
+                self.head.as_ref().unwrap().borrow_mut().prev = Some(Rc::downgrade(&node_ptr));
+                self.head = Some(node_ptr);
                 self.len += 1;
             }
         }
@@ -290,6 +305,8 @@ where
             _ => {
                 self.tail.as_ref().unwrap().borrow_mut().insert(val);
                 let tail_next_ptr = self.tail.as_ref().unwrap().borrow().next();
+                tail_next_ptr.as_ref().unwrap().borrow_mut().prev =
+                    self.tail.as_ref().map(Rc::downgrade);
                 self.tail = tail_next_ptr;
                 self.len += 1;
             }
@@ -347,6 +364,7 @@ where
             _ => {
                 let next_node_ptr = self.head.as_ref().unwrap().borrow().next();
                 let val = self.head.as_ref().unwrap().borrow().value.clone();
+                next_node_ptr.as_ref().unwrap().borrow_mut().prev = None;
                 self.head = next_node_ptr;
                 self.len -= 1;
                 Ok(val)
@@ -391,19 +409,17 @@ where
     ///
     /// | Time | Space |
     /// | --- | --- |
-    /// | O(n) | O(1) |
+    /// | O(1) | O(1) |
     pub fn pop_back(&mut self) -> Result<T, LinkedListError> {
         match self.len {
             0 => Err(LinkedListError::EmptyList),
             1 => self.pop_head(),
             _ => {
-                let mut curr = self.head.as_ref().unwrap().clone();
-                for _ in 0..self.len - 2 {
-                    let node = curr.borrow().next.as_ref().unwrap().clone();
-                    curr = node;
-                }
-                let val = curr.borrow_mut().remove().unwrap();
-                self.tail = Some(curr);
+                // self.len >= 2 here, so the tail always has a live predecessor
+                let tail = self.tail.take().unwrap();
+                let prev = tail.borrow().prev.as_ref().unwrap().upgrade().unwrap();
+                let val = prev.borrow_mut().remove().unwrap();
+                self.tail = Some(prev);
                 self.len -= 1;
                 Ok(val)
             }
@@ -458,14 +474,23 @@ where
         if at == 0 {
             self.push_head(val);
             Ok(())
-        } else if (0 < at) && (at < self.len + 1) {
+        } else if at == self.len {
+            self.push_back(val);
+            Ok(())
+        } else if (0 < at) && (at < self.len) {
             let mut wraped_ptr = self.head.clone();
             for _ in 0..at - 1 {
                 let x = wraped_ptr.as_ref().unwrap().borrow().next.clone();
                 wraped_ptr = x;
             }
 
-            wraped_ptr.as_ref().unwrap().borrow_mut().insert(val);
+            let prev = wraped_ptr.unwrap();
+            prev.borrow_mut().insert(val);
+
+            // fix the back-links on both sides of the new node
+            let node = prev.borrow().next().unwrap();
+            node.borrow_mut().prev = Some(Rc::downgrade(&prev));
+            node.borrow().next.as_ref().unwrap().borrow_mut().prev = Some(Rc::downgrade(&node));
             self.len += 1;
 
             Ok(())
@@ -538,9 +563,14 @@ where
                 curr = node;
             }
             let val = curr.borrow_mut().remove().unwrap();
+
+            // the node after the removed one now points back to `curr`, or `curr` is the new tail
+            match curr.borrow().next() {
+                Some(next) => next.borrow_mut().prev = Some(Rc::downgrade(&curr)),
+                None => self.tail = Some(curr.clone()),
+            }
             self.len -= 1;
             Ok(val)
-            /* curr.borrow_mut().remove() // why not ? */
         } else {
             Err(LinkedListError::RemoveOutOfRange)
         }
@@ -584,7 +614,7 @@ where
     /// | --- | --- |
     /// | O(n) | O(1) |
     pub fn val2ix(&self, val: &T) -> Vec<usize> {
-        if self.len() == 0 {
+        if self.is_empty() {
             return vec![];
         }
         let mut curr = self.head.as_ref().unwrap().clone();
@@ -755,6 +785,142 @@ where
     pub fn no_move_into_iter(&self) -> LinkedListIterator<T> {
         LinkedListIterator::new(self.head.clone()) // use clone to avoid move of self.head if you use Box<> impled LinkedList this is not able to complemented
     }
+
+    /// Counts the occurrences of a given value in the list.
+    ///
+    /// Unlike `val2ix(&val).len()`, this allocates nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to count.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements equal to `val`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::rc_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 1, 3, 1]);
+    /// assert_eq!(list.count(&1), 3);
+    /// assert_eq!(list.count(&4), 0);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | --- | --- |
+    /// | O(n) | O(1) |
+    pub fn count(&self, val: &T) -> usize {
+        let mut count = 0;
+        let mut curr = self.head.clone();
+        while let Some(node) = curr {
+            if node.borrow().value == *val {
+                count += 1;
+            }
+            curr = node.borrow().next();
+        }
+        count
+    }
+
+    /// Returns an iterator over clones of the values in the list.
+    ///
+    /// This is the same iterator as `no_move_into_iter`, named like the `iter` of the other lists.
+    /// The values live behind `RefCell`s, whose borrows end as soon as each `next` call returns, so
+    /// the iterator can only hand out owned clones, not `&T`.
+    ///
+    /// # Returns
+    ///
+    /// A `LinkedListIterator<T>` yielding a clone of each value, from head to tail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::rc_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.iter().sum::<i32>(), 6);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | --- | --- |
+    /// | O(n) | O(1) |
+    pub fn iter(&self) -> LinkedListIterator<T> {
+        self.no_move_into_iter()
+    }
+
+    /// Applies `f` to every value in the list, in order, allowing it to modify them in place.
+    ///
+    /// A real `iter_mut` returning `&mut T` cannot be written for this list: the reference would have
+    /// to outlive the `RefMut` guard of its node, which is what keeps the `RefCell` marked as borrowed.
+    /// Instead, each node is borrowed mutably in turn and the borrow is released before moving on.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure applied to each value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::rc_linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// list.for_each_mut(|x| *x *= 2);
+    /// assert_eq!(format!("{}", list), "(2 -> 4 -> 6)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | --- | --- |
+    /// | O(n) | O(1) |
+    pub fn for_each_mut<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        let mut curr = self.head.clone();
+        while let Some(node) = curr {
+            let mut node_ref = node.borrow_mut();
+            f(&mut node_ref.value);
+            curr = node_ref.next.clone();
+        }
+    }
+
+    /// Returns an iterator over clones of the values in the list, from tail to head.
+    ///
+    /// The walk follows the `prev` links back from `tail`, so unlike collecting the list into a
+    /// `Vec` and reversing it, this needs no extra space.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding a clone of each value, from tail to head.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::rc_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::from_iter(vec![1, 2, 3]);
+    /// assert_eq!(list.iter_rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | --- | --- |
+    /// | O(n) | O(1) |
+    pub fn iter_rev(&self) -> impl Iterator<Item = T> {
+        let mut curr = self.tail.clone();
+        std::iter::from_fn(move || {
+            let node = curr.take()?;
+            let node_ref = node.borrow();
+            curr = node_ref.prev.as_ref().and_then(Weak::upgrade);
+            let val = node_ref.value.clone();
+            Some(val)
+        })
+    }
 }
 
 impl<T> Default for LinkedList<T> {
@@ -780,6 +946,22 @@ where
     }
 }
 
+impl<T> Drop for LinkedList<T> {
+    /// Unlinks the nodes one at a time instead of letting each `Rc` drop the next one recursively,
+    /// which would overflow the stack on long lists.
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut curr = self.head.take();
+        while let Some(node) = curr {
+            match Rc::try_unwrap(node) {
+                Ok(node) => curr = node.into_inner().next,
+                // The rest of the chain is still shared with a clone of this list, which drops it later.
+                Err(_) => break,
+            }
+        }
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for LinkedList<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "(")?;
@@ -806,8 +988,8 @@ impl<T: Clone> IntoIterator for LinkedList<T> {
     type Item = T;
     type IntoIter = LinkedListIterator<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        LinkedListIterator::new(self.head) // do not use self.head.clone here is to avoid rc::ref + 1
+    fn into_iter(mut self) -> Self::IntoIter {
+        LinkedListIterator::new(self.head.take()) // do not use self.head.clone here is to avoid rc::ref + 1
     }
 }
 
@@ -1098,6 +1280,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_from_iter() {
         // Test creating a list from a vector
         let list: LinkedList<i32> = LinkedList::from_iter(vec![]);
@@ -1147,4 +1330,152 @@ mod tests {
         list.push_back(1);
         assert!(!list.is_empty());
     }
+
+    /// Walks the list forwards via `next` and backwards via `prev`, checking both agree with `head`, `tail` and `len`.
+    fn assert_links<T: Clone + PartialEq + fmt::Debug>(list: &LinkedList<T>) {
+        let mut forward = vec![];
+        let mut curr = list.head.clone();
+        let mut last = None;
+        while let Some(node) = curr {
+            forward.push(node.borrow().value.clone());
+            curr = node.borrow().next();
+            last = Some(node);
+        }
+        assert_eq!(forward.len(), list.len());
+        assert_eq!(last.is_some(), list.tail.is_some());
+        if let (Some(last), Some(tail)) = (last, list.tail.as_ref()) {
+            assert!(Rc::ptr_eq(&last, tail)); // tail is the last node reachable from head
+        }
+
+        let mut backward = vec![];
+        let mut curr = list.tail.clone();
+        while let Some(node) = curr {
+            backward.push(node.borrow().value.clone());
+            curr = node
+                .borrow()
+                .prev
+                .as_ref()
+                .map(|prev| prev.upgrade().unwrap());
+        }
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_prev_links() {
+        let mut list = LinkedList::new();
+        assert_links(&list);
+
+        list.push_back(2);
+        list.push_head(1);
+        list.push_back(4);
+        list.insert(3, 2).unwrap(); // List: 1 -> 2 -> 3 -> 4
+        list.insert(5, 4).unwrap(); // List: 1 -> 2 -> 3 -> 4 -> 5
+        assert_links(&list);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3 -> 4 -> 5)");
+
+        assert_eq!(list.remove(4), Ok(5)); // Remove the tail by index
+        assert_eq!(list.remove(1), Ok(2));
+        assert_links(&list);
+        assert_eq!(format!("{}", list), "(1 -> 3 -> 4)");
+
+        list.push_back(6); // tail must still be valid after remove
+        assert_links(&list);
+        assert_eq!(format!("{}", list), "(1 -> 3 -> 4 -> 6)");
+    }
+
+    #[test]
+    fn test_push_pop_back_cycles() {
+        let mut list = LinkedList::new();
+        for round in 0..100 {
+            for i in 0..50 {
+                list.push_back(round * 100 + i);
+            }
+            for i in (25..50).rev() {
+                assert_eq!(list.pop_back(), Ok(round * 100 + i));
+            }
+            assert_links(&list);
+        }
+        assert_eq!(list.len(), 100 * 25);
+
+        while list.pop_back().is_ok() {}
+        assert!(list.is_empty());
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_count() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.count(&1), 0); // Empty list
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(3);
+        list.push_back(1);
+        assert_eq!(list.count(&1), 3); // 1 appears three times
+        assert_eq!(list.count(&2), 1);
+        assert_eq!(list.count(&4), 0); // No 4 in the list
+        assert_eq!(list.count(&1), list.val2ix(&1).len());
+    }
+
+    #[test]
+    fn test_for_each_mut() {
+        let mut list = LinkedList::from_iter(vec![1, 2, 3]);
+        list.for_each_mut(|x| *x *= 2); // Double every element
+        assert_eq!(format!("{}", list), "(2 -> 4 -> 6)");
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![2, 4, 6]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.for_each_mut(|_| panic!("closure called on an empty list"));
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn test_drop_shared_clone() {
+        // A clone shares its nodes, so dropping it must leave the original intact
+        let list = LinkedList::from_iter(vec![1, 2, 3]);
+        let clone = list.clone();
+        drop(clone);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[ignore] // slow in debug builds; run with `cargo test -- --ignored`
+    fn test_drop_long_list() {
+        // Dropping a long list must not recurse once per node
+        let mut list = LinkedList::new();
+        for i in 0..1_000_000 {
+            list.push_back(i);
+        }
+        drop(list);
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut list = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(list.iter_rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        // Still correct after editing both ends
+        list.pop_back().unwrap();
+        list.push_head(0);
+        assert_eq!(list.iter_rev().collect::<Vec<_>>(), vec![2, 1, 0]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.iter_rev().next(), None);
+    }
+
+    #[test]
+    fn test_default() {
+        let list: LinkedList<i32> = LinkedList::default();
+        assert!(list.is_empty());
+
+        // mem::take leaves an empty list behind
+        let mut list = LinkedList::from_iter(vec![1, 2]);
+        let taken = std::mem::take(&mut list);
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", taken), "(1 -> 2)");
+    }
 }
@@ -1,27 +1,139 @@
-use crate::LinkedList;
-use crate::LinkedListError;
+use crate::box_linked_list::LinkedList;
+use crate::box_linked_list::LinkedListError;
 
+/// A LIFO stack backed by the box linked list, optionally capped at a maximum length.
+///
+/// # Examples
+///
+/// ```rust
+/// use hym::stack::Stack;
+///
+/// let mut stack = Stack::with_capacity(2);
+/// assert_eq!(stack.try_push(1), Ok(()));
+/// assert_eq!(stack.try_push(2), Ok(()));
+/// assert_eq!(stack.try_push(3), Err(3)); // full, the item is handed back
+/// assert_eq!(stack.pop(), Ok(2));
+/// ```
 #[derive(Debug)]
-struct Stack<T> {
+pub struct Stack<T> {
     list: LinkedList<T>,
+    max_len: Option<usize>, // `None` for an unbounded stack.
 }
 
-impl<T: Clone + std::cmp::PartialOrd> Stack<T> {
-    fn new() -> Stack<T> {
+impl<T: Clone + core::cmp::PartialOrd> Stack<T> {
+    /// Creates a new, unbounded stack.
+    pub fn new() -> Stack<T> {
         Stack {
             list: LinkedList::new(),
+            max_len: None,
         }
     }
 
-    fn push(&mut self, item: T) {
+    /// Creates a new stack that `try_push` keeps at no more than `cap` items.
+    pub fn with_capacity(cap: usize) -> Stack<T> {
+        Stack {
+            list: LinkedList::new(),
+            max_len: Some(cap),
+        }
+    }
+
+    /// Pushes an item onto the stack. This never checks the cap; use `try_push` for that.
+    pub fn push(&mut self, item: T) {
+        self.list.push_head(item);
+    }
+
+    /// Pushes an item onto the stack, or hands it back in `Err` if the stack is at its cap.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.max_len.is_some_and(|cap| self.list.len() >= cap) {
+            return Err(item);
+        }
         self.list.push_head(item);
+        Ok(())
     }
 
-    fn pop(&mut self) -> Result<T, LinkedListError> {
+    /// Removes and returns the top item.
+    pub fn pop(&mut self) -> Result<T, LinkedListError> {
         self.list.pop_head()
     }
 
-    fn is_empty(&self) -> bool {
+    /// Returns the item `n` positions below the top, `n = 0` being the top, or `None` past the bottom.
+    pub fn peek_nth(&self, n: usize) -> Option<&T> {
+        self.list.iter().nth(n)
+    }
+
+    /// Returns an iterator over the items, from the top of the stack down.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator over mutable references to the items, from the top of the stack down.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.list.iter_mut()
+    }
+
+    /// Returns `true` if the stack holds no items.
+    pub fn is_empty(&self) -> bool {
         self.list.is_empty()
     }
 }
+
+impl<T: Clone + core::cmp::PartialOrd> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Unit Test for Stack
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_push_capacity() {
+        let mut stack = Stack::with_capacity(2);
+        assert_eq!(stack.try_push(1), Ok(()));
+        assert_eq!(stack.try_push(2), Ok(())); // Now full
+        assert_eq!(stack.try_push(3), Err(3)); // Rejected, the item is handed back
+
+        assert_eq!(stack.pop(), Ok(2)); // Make room
+        assert_eq!(stack.try_push(4), Ok(()));
+        assert_eq!(stack.pop(), Ok(4));
+        assert_eq!(stack.pop(), Ok(1));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_peek_nth() {
+        let mut stack = Stack::new();
+        for i in 1..=3 {
+            stack.push(i);
+        }
+        assert_eq!(stack.peek_nth(0), Some(&3)); // Top
+        assert_eq!(stack.peek_nth(1), Some(&2)); // Second from the top
+        assert_eq!(stack.peek_nth(2), Some(&1)); // Bottom
+        assert_eq!(stack.peek_nth(3), None); // Beyond the depth
+        assert_eq!(stack.pop(), Ok(3)); // Peeking does not remove anything
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut stack = Stack::new();
+        for i in 1..=3 {
+            stack.push(i);
+        }
+        for item in stack.iter_mut() {
+            *item *= *item; // Square every item in place
+        }
+        assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&9, &4, &1]); // Top first
+        assert_eq!(stack.pop(), Ok(9));
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let mut stack = Stack::new();
+        for i in 0..100 {
+            assert_eq!(stack.try_push(i), Ok(())); // No cap
+        }
+        assert_eq!(stack.pop(), Ok(99));
+    }
+}
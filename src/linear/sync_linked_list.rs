@@ -0,0 +1,364 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// `LinkedListNode` represents a single node in a thread-safe linked list, containing a value and a
+/// shared, lockable reference to the next node.
+#[derive(Debug)]
+pub struct LinkedListNode<T> {
+    value: T,                                    // The value stored in the node.
+    next: Option<Arc<Mutex<LinkedListNode<T>>>>, // A reference to the next node in the list, if any.
+}
+
+/// `Ends` is the part of the list behind the list-level lock: its length and both ends.
+#[derive(Debug)]
+struct Ends<T> {
+    len: usize,                                  // The length of the list.
+    head: Option<Arc<Mutex<LinkedListNode<T>>>>, // A reference to the first node in the list.
+    tail: Option<Arc<Mutex<LinkedListNode<T>>>>, // A reference to the last node in the list.
+}
+
+/// A thread-safe singly linked list, the `Arc<Mutex<...>>` counterpart of the Rc/RefCell list.
+///
+/// # Explanation
+///
+/// Every method takes `&self`, so one list can be shared between threads (for example through an
+/// `Arc<LinkedList<T>>`) and mutated from all of them. The length and both ends sit behind one
+/// list-level `Mutex`; each node has its own `Mutex` as well, so it can be reached from both its
+/// predecessor and `tail`. Operations always lock the list first and then nodes from head to tail,
+/// so two operations never wait on each other in a cycle.
+///
+/// `LinkedList<T>` is `Send` and `Sync` whenever `T` is `Send`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::thread;
+/// use hym::sync_linked_list::LinkedList;
+///
+/// let list = Arc::new(LinkedList::new());
+/// let handles: Vec<_> = (0..2)
+///     .map(|t| {
+///         let list = Arc::clone(&list);
+///         thread::spawn(move || list.push_back(t))
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// assert_eq!(list.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct LinkedList<T> {
+    ends: Mutex<Ends<T>>,
+}
+
+/// Enum for different types of errors that can occur while manipulating the linked list.
+///
+/// # Explanation
+///
+/// - EmptyList: The list is empty.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkedListError {
+    EmptyList, // Error when the list is empty.
+}
+
+/// Locks a mutex, ignoring poisoning: no operation of the list leaves it half-updated while a lock
+/// is held and a panic can happen, so the data behind a poisoned lock is still consistent.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T> LinkedList<T> {
+    /// Creates a new, empty linked list.
+    ///
+    /// # Returns
+    ///
+    /// A new, empty `LinkedList`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::sync_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// assert_eq!(format!("{}", list), "()");
+    /// ```
+    pub fn new() -> Self {
+        LinkedList {
+            ends: Mutex::new(Ends {
+                len: 0,
+                head: None,
+                tail: None,
+            }),
+        }
+    }
+
+    /// Adds a new node with the given value to the front (head) of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to be added to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::sync_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// list.push_head(1);
+    /// list.push_head(2);
+    /// assert_eq!(format!("{}", list), "(2 -> 1)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | ---- | ----- |
+    /// | O(1) | O(1) |
+    pub fn push_head(&self, val: T) {
+        let mut ends = lock(&self.ends);
+        let node = Arc::new(Mutex::new(LinkedListNode {
+            value: val,
+            next: ends.head.take(),
+        }));
+
+        if ends.tail.is_none() {
+            ends.tail = Some(Arc::clone(&node));
+        }
+        ends.head = Some(node);
+        ends.len += 1;
+    }
+
+    /// Adds a new node with the given value to the end (tail) of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The value to be added to the end of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::sync_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// assert_eq!(format!("{}", list), "(1 -> 2)");
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | ---- | ----- |
+    /// | O(1) | O(1) |
+    pub fn push_back(&self, val: T) {
+        let mut ends = lock(&self.ends);
+        let node = Arc::new(Mutex::new(LinkedListNode {
+            value: val,
+            next: None,
+        }));
+
+        match ends.tail.take() {
+            Some(old_tail) => lock(&old_tail).next = Some(Arc::clone(&node)),
+            None => ends.head = Some(Arc::clone(&node)),
+        }
+        ends.tail = Some(node);
+        ends.len += 1;
+    }
+
+    /// Removes and returns the value at the front (head) of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The value from the removed head node.
+    /// * `Err(LinkedListError)` - An error if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::sync_linked_list::{LinkedList, LinkedListError};
+    ///
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// list.push_back(1);
+    /// assert_eq!(list.pop_head(), Ok(1));
+    /// assert_eq!(list.pop_head(), Err(LinkedListError::EmptyList));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// | Time | Space |
+    /// | ---- | ----- |
+    /// | O(1) | O(1) |
+    pub fn pop_head(&self) -> Result<T, LinkedListError> {
+        let mut ends = lock(&self.ends);
+        let head = ends.head.take().ok_or(LinkedListError::EmptyList)?;
+
+        ends.head = lock(&head).next.take();
+        if ends.head.is_none() {
+            ends.tail = None;
+        }
+        ends.len -= 1;
+
+        // Only the list holds references to its nodes, and both `head` and `tail` have let go of
+        // this one, so it is the last reference.
+        match Arc::try_unwrap(head) {
+            Ok(node) => Ok(node
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner)
+                .value),
+            Err(_) => unreachable!("a removed node is still shared"),
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::sync_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// list.push_back(1);
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        lock(&self.ends).len
+    }
+
+    /// Checks if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the list is empty, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hym::sync_linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<i32> = LinkedList::new();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    /// Unlinks the nodes one at a time instead of letting each `Arc` drop the next one recursively,
+    /// which would overflow the stack on long lists.
+    fn drop(&mut self) {
+        let ends = self.ends.get_mut().unwrap_or_else(PoisonError::into_inner);
+        ends.tail = None;
+        let mut curr = ends.head.take();
+        while let Some(node) = curr {
+            curr = match Arc::try_unwrap(node) {
+                Ok(node) => {
+                    node.into_inner()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .next
+                }
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+
+        // Holding the list lock keeps other threads from changing the list halfway through.
+        let ends = lock(&self.ends);
+        let mut curr = ends.head.clone();
+        let mut first = true;
+
+        while let Some(node) = curr {
+            let node_ref = lock(&node);
+            if !first {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", node_ref.value)?;
+            first = false;
+            curr = node_ref.next.clone();
+        }
+
+        write!(f, ")")?;
+        Ok(())
+    }
+}
+
+// Unit Test for LinkedList
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_api() {
+        // Same behaviour as the Rc list, single-threaded
+        let list = LinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.pop_head(), Err(LinkedListError::EmptyList));
+
+        list.push_back(2);
+        list.push_head(1);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{}", list), "(1 -> 2 -> 3)");
+
+        assert_eq!(list.pop_head(), Ok(1));
+        assert_eq!(list.pop_head(), Ok(2));
+        assert_eq!(list.pop_head(), Ok(3)); // Also clears the tail
+        assert_eq!(format!("{}", list), "()");
+
+        list.push_back(4); // so pushing to the back starts a new list
+        assert_eq!(format!("{}", list), "(4)");
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<LinkedList<i32>>();
+    }
+
+    #[test]
+    fn test_push_from_threads() {
+        // Two threads push to the same list at the same time
+        let list = Arc::new(LinkedList::new());
+        let handles: Vec<_> = (0..2)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    for i in 0..1000 {
+                        list.push_back(t * 1000 + i);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(list.len(), 2000);
+
+        // Each thread's elements keep their relative order
+        let mut seen = [Vec::new(), Vec::new()];
+        while let Ok(val) = list.pop_head() {
+            seen[val / 1000].push(val % 1000);
+        }
+        assert_eq!(seen[0], (0..1000).collect::<Vec<_>>());
+        assert_eq!(seen[1], (0..1000).collect::<Vec<_>>());
+    }
+}